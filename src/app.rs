@@ -1,73 +1,1508 @@
-use crate::game_logic::WordleGame;
-use crate::states::{GameState, LetterState};
-use crate::utils::selector::pick_random_word;
+use crate::game_logic::{
+    score_guess, Candidate, MultiBoardGame, WordleGame, DEFAULT_ATTEMPTS, MAX_ATTEMPTS, MAX_BOARDS,
+};
+use crate::hotseat::HotSeatMatch;
+use crate::language::{Language, TileScheme};
+use crate::reverse::{ReverseGame, ReverseResult};
+use crate::states::{Difficulty, GameMode, GameState, LetterState};
+use crate::symbols::{self, SYMBOL_SETS};
+use crate::utils::daily::{self, DailyResult, DailyResults, DailySchedule};
+use crate::utils::definition::Definition;
+use crate::utils::history::{record_game, GameRecord};
+use crate::utils::marathon::{MarathonRecord, MarathonRun, MarathonTable};
+use crate::utils::personal::{allow_word, deny_word};
+use crate::utils::puzzle::{PuzzleCode, MIN_WORD_LENGTH};
+use crate::utils::selector::AnswerPool;
+use crate::utils::share::{parse_share_string, pattern_squares, share_string};
+use crate::utils::store::DefinitionStore;
+use crate::utils::timed::{self, PersonalBests, TimedRun, TimedWord, TimerKind};
 use crate::WORLD_LENGTH;
 use eframe::egui;
 use egui::{RichText, Vec2, ViewportBuilder};
-use std::collections::HashMap;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
 use std::sync::Arc;
 
+/// Space between the boards of a multi-board game
+const BOARD_SPACING: f32 = 12.0;
+
+/// Number of random words whose colors make up a random reverse puzzle
+const REVERSE_ROWS: usize = 3;
+
+/// Number of random answers tried for a shared grid before giving up
+const REVERSE_TRIES: usize = 50;
+
+/// Helper function for the color of a key of the keyboard
+fn key_color(state: LetterState) -> egui::Color32 {
+    match state {
+        LetterState::Correct => egui::Color32::GREEN,
+        LetterState::Present => egui::Color32::YELLOW,
+        LetterState::Wrong => egui::Color32::from_gray(50),
+        LetterState::Unknown => egui::Color32::from_gray(80),
+    }
+}
+
 /// Struct to keep all the information of the game together
-/// This struct creates the 5 letter grid with 6 attempts and holds the word and its defintion
+/// This struct creates the 5 letter grid with 6 attempts (or as many as a shared puzzle sets) and holds the word and its defintion
+/// The definitions are only read from the dictionary once the game is over
+/// Several boards can be played at once, they share the input row
+/// The letters of the grid and the keyboard come from the language the game is played in
 #[derive(Debug)]
 pub struct MyEguiApp {
-    grid: [[char; WORLD_LENGTH]; WORLD_LENGTH + 1],
+    language: &'static Language,
     current_row: usize,
     word: String,
-    definition: String,
-    game_logic: WordleGame,
+    definitions: Vec<(String, Definition)>,
+    game_logic: MultiBoardGame,
     game_state: Option<GameState>,
     last_guessed_word: Option<String>,
     index: usize,
-    dictionary: Arc<HashMap<String, String>>,
+    dictionary: Arc<DefinitionStore>,
+    answers: Arc<AnswerPool>,
+    accepted_guesses: Arc<HashSet<String>>,
+    difficulty: Difficulty,
+    word_difficulty: Difficulty,
+    word_denied: bool,
+    mode: GameMode,
+    daily_schedule: Arc<DailySchedule>,
+    daily_results: DailyResults,
+    show_archive: bool,
+    puzzle_windows: PuzzleWindows,
+    board_count: usize,
+    timed_run: Option<TimedRun>,
+    personal_bests: PersonalBests,
+    new_best: bool,
+    marathon: Option<MarathonRun>,
+    marathon_table: MarathonTable,
+    /// place of the last finished marathon in the best-run table
+    marathon_place: Option<usize>,
+    show_marathon_table: bool,
+    reverse: Option<ReverseGame>,
+    /// number of words that give the colors of each row of the reverse game
+    reverse_hints: Vec<usize>,
+    /// the colors the last guess of the reverse game gave instead
+    reverse_message: Option<String>,
+    reverse_window: ReverseWindow,
+    /// the symbols of a custom Mastermind set, separated by spaces
+    symbol_text: String,
+    hot_seat: Option<HotSeatMatch>,
+    hot_seat_window: HotSeatWindow,
+    /// a new game that waits for the player to confirm leaving the running one
+    pending_game: Option<NewGame>,
+}
+
+/// Enum for the games the buttons start, so a game that was asked for can wait for confirmation
+#[derive(Debug, Clone, Copy)]
+enum NewGame {
+    Random,
+    Daily(u64),
+    Absurdle,
+    Timed(TimerKind),
+    Marathon,
+    Mastermind(&'static Language),
+    MultiBoard(usize),
+}
+
+/// State of the window of a two-player match: the names and match length to start with and the word the setter enters
+#[derive(Debug)]
+struct HotSeatWindow {
+    show: bool,
+    names: [String; 2],
+    best_of: Option<usize>,
+    word: String,
+    error: Option<String>,
+}
+
+impl Default for HotSeatWindow {
+    fn default() -> Self {
+        Self {
+            show: false,
+            names: ["Player 1".to_string(), "Player 2".to_string()],
+            best_of: Some(3),
+            word: String::new(),
+            error: None,
+        }
+    }
+}
+
+/// State of the window to play a share string in reverse mode
+#[derive(Debug, Default)]
+struct ReverseWindow {
+    show: bool,
+    text: String,
+    error: Option<String>,
+}
+
+/// State of the windows to create a puzzle and to play a puzzle code
+#[derive(Debug)]
+struct PuzzleWindows {
+    show_create: bool,
+    show_play: bool,
+    word: String,
+    /// the length of the word, up to the word length of the language
+    length: usize,
+    attempts: usize,
+    /// the code of the created puzzle, or why the word can't be used
+    created: Option<Result<String, String>>,
+    code: String,
+    error: Option<String>,
+}
+
+impl Default for PuzzleWindows {
+    fn default() -> Self {
+        Self {
+            show_create: false,
+            show_play: false,
+            word: String::new(),
+            length: WORLD_LENGTH,
+            attempts: DEFAULT_ATTEMPTS,
+            created: None,
+            code: String::new(),
+            error: None,
+        }
+    }
 }
 
 /// Methods for the Gui App
 impl MyEguiApp {
     /// Constructor method for the app.
-    /// This method takes in the word that is supposed to be guessed and it's defintion
+    /// This method takes in the word that is supposed to be guessed
     pub fn new(
-        _cc: &eframe::CreationContext<'_>,
+        cc: &eframe::CreationContext<'_>,
         word: String,
-        definition: String,
-        dict: Arc<HashMap<String, String>>,
+        dict: Arc<DefinitionStore>,
+        answers: Arc<AnswerPool>,
+        accepted_guesses: Arc<HashSet<String>>,
+        daily_schedule: Arc<DailySchedule>,
+        language: &'static Language,
     ) -> Self {
+        Self::load_fonts(&cc.egui_ctx, language);
+        let daily_results = DailyResults::load(language).unwrap_or_else(|e| {
+            println!("Error reading your daily results: {}", e);
+            DailyResults::default()
+        });
+
         Self {
-            grid: [[' '; WORLD_LENGTH]; WORLD_LENGTH + 1],
+            language,
             current_row: 0,
             word: word.clone(),
-            definition: definition,
-            game_logic: WordleGame::new(word),
+            definitions: Vec::new(),
+            game_logic: MultiBoardGame::single(WordleGame::new(word, language)),
             game_state: None,
             index: 0,
             dictionary: dict,
+            answers,
+            accepted_guesses,
+            difficulty: Difficulty::Normal,
+            word_difficulty: Difficulty::Normal,
+            word_denied: false,
             last_guessed_word: None,
+            mode: GameMode::Random,
+            daily_schedule,
+            daily_results,
+            show_archive: false,
+            puzzle_windows: PuzzleWindows {
+                length: language.word_length,
+                ..Default::default()
+            },
+            board_count: 4,
+            timed_run: None,
+            personal_bests: PersonalBests::load().unwrap_or_else(|e| {
+                println!("Error reading your personal bests: {}", e);
+                PersonalBests::default()
+            }),
+            new_best: false,
+            marathon: None,
+            marathon_table: MarathonTable::load(language).unwrap_or_else(|e| {
+                println!("Error reading your best marathons: {}", e);
+                MarathonTable::default()
+            }),
+            marathon_place: None,
+            show_marathon_table: false,
+            reverse: None,
+            reverse_hints: Vec::new(),
+            reverse_message: None,
+            reverse_window: ReverseWindow::default(),
+            symbol_text: String::new(),
+            hot_seat: None,
+            hot_seat_window: HotSeatWindow::default(),
+            pending_game: None,
+        }
+    }
+
+    /// Helper function that loads a font for alphabets the default fonts can't display (Hangul, ...).
+    /// The first font of the language that exists on this system is added as a fallback.
+    fn load_fonts(ctx: &egui::Context, language: &Language) {
+        let Some(font_data) = language
+            .fonts
+            .iter()
+            .find_map(|path| std::fs::read(path).ok())
+        else {
+            if !language.fonts.is_empty() {
+                println!(
+                    "No font found for {}, letters may not display",
+                    language.name
+                );
+            }
+            return;
+        };
+
+        let mut fonts = egui::FontDefinitions::default();
+        fonts.font_data.insert(
+            language.code.to_string(),
+            egui::FontData::from_owned(font_data),
+        );
+        for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+            fonts
+                .families
+                .entry(family)
+                .or_default()
+                .push(language.code.to_string());
+        }
+        ctx.set_fonts(fonts);
+    }
+
+    fn new_game(&mut self) {
+        if let Some(word) = self.pick_word() {
+            self.start_game(word, self.difficulty, DEFAULT_ATTEMPTS);
+        }
+    }
+
+    /// Helper function that picks a random word of the chosen difficulty
+    fn pick_word(&self) -> Option<String> {
+        match self.answers.pick(self.difficulty) {
+            Ok(word) => Some(word),
+            Err(e) => {
+                println!("Error: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Method that resets the board for a new answer
+    fn start_game(&mut self, word: String, difficulty: Difficulty, attempts: usize) {
+        self.reset_board(
+            MultiBoardGame::single(WordleGame::with_attempts(word, self.language, attempts)),
+            difficulty,
+        );
+    }
+
+    /// Method that starts a game with several boards, every board gets another random word of the chosen difficulty
+    fn start_multi_board(&mut self, boards: usize) {
+        // the pool of the difficulty can have fewer words than there are boards
+        let words = self.answers.pick_several(self.difficulty, boards);
+        if words.len() < boards {
+            println!("There are not enough words for {} boards", boards);
+            return;
+        }
+
+        self.reset_board(MultiBoardGame::new(words, self.language), self.difficulty);
+        self.mode = GameMode::MultiBoard { boards };
+    }
+
+    /// Method that starts an Absurdle game: every answer of the word length is a candidate and none is picked yet.
+    /// The answer is only known once the game is over.
+    fn start_absurdle(&mut self) {
+        let candidates: Vec<Candidate> = self
+            .answers
+            .words()
+            .iter()
+            .map(|word| Candidate::new(word.clone(), self.language))
+            .collect();
+        if candidates.is_empty() {
+            println!("The dictionary is empty.");
+            return;
+        }
+
+        self.reset_board(
+            MultiBoardGame::single(WordleGame::adversarial(
+                candidates,
+                self.language,
+                DEFAULT_ATTEMPTS,
+            )),
+            Difficulty::Normal,
+        );
+        self.mode = GameMode::Absurdle;
+    }
+
+    /// Method that starts a timed run with a random word, the clock starts right away
+    fn start_timed_run(&mut self, kind: TimerKind) {
+        self.new_game();
+        self.timed_run = Some(TimedRun::new(kind, self.language.word_length));
+        self.new_best = false;
+        self.mode = match kind {
+            TimerKind::Countdown { minutes } => GameMode::Timed { minutes },
+            TimerKind::Stopwatch => GameMode::SpeedRun,
+        };
+    }
+
+    /// Method that moves a session (a timed run or a marathon) on to its next word.
+    /// A new game resets the board, so the state that spans the games of the session is kept aside meanwhile.
+    fn next_session_word(&mut self, attempts: usize) {
+        let Some(word) = self.pick_word() else {
+            return;
+        };
+
+        let mode = self.mode;
+        let timed_run = self.timed_run.take();
+        let marathon = self.marathon.take();
+        self.start_game(word, self.difficulty, attempts);
+        self.timed_run = timed_run;
+        self.marathon = marathon;
+        self.mode = mode;
+    }
+
+    /// Method that starts a Mastermind game with a random code of a symbol set
+    fn start_mastermind(&mut self, symbols: &'static Language) {
+        let code = symbols::random_code(symbols);
+        self.reset_board(
+            MultiBoardGame::single(WordleGame::mastermind(code, symbols, MAX_ATTEMPTS)),
+            Difficulty::Normal,
+        );
+        self.mode = GameMode::Mastermind;
+    }
+
+    /// Method that starts a round of a two-player match with the word the setter entered
+    fn start_hot_seat_round(&mut self) -> Result<(), String> {
+        let word = self.check_word(&self.hot_seat_window.word)?;
+
+        self.start_game(word, Difficulty::Normal, DEFAULT_ATTEMPTS);
+        self.mode = GameMode::HotSeat;
+        self.hot_seat_window.word.clear();
+        Ok(())
+    }
+
+    /// Helper function for the scoreboard of a two-player match and the rounds played so far
+    fn show_scoreboard(ui: &mut egui::Ui, hot_seat: &HotSeatMatch) {
+        egui::Grid::new("scoreboard").striped(true).show(ui, |ui| {
+            ui.label(RichText::new("Player").strong());
+            ui.label(RichText::new("Rounds won").strong());
+            ui.label(RichText::new("Guesses").strong());
+            ui.end_row();
+            for player in &hot_seat.players {
+                ui.label(&player.name);
+                ui.label(player.wins.to_string());
+                ui.label(player.guesses.to_string());
+                ui.end_row();
+            }
+        });
+
+        for (i, round) in hot_seat.rounds.iter().enumerate() {
+            let guesser = &hot_seat.players[1 - round.setter].name;
+            let result = if round.won {
+                format!("{} solved it in {}", guesser, round.guesses)
+            } else {
+                format!("{} didn't solve it", guesser)
+            };
+            ui.label(format!(
+                "Round {}: {} set the word, {}",
+                i + 1,
+                hot_seat.players[round.setter].name,
+                result
+            ));
+        }
+    }
+
+    /// Function for the window of a two-player match on one machine.
+    /// Before a round the setter enters the word hidden, while it is played the window only shows the scoreboard.
+    fn show_hot_seat_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.hot_seat_window.show;
+        let round_running = self.mode == GameMode::HotSeat
+            && !matches!(self.game_state, Some(GameState::Won | GameState::Lost));
+
+        egui::Window::new("Two Players")
+            .open(&mut open)
+            .show(ctx, |ui| {
+                let Some(hot_seat) = &self.hot_seat else {
+                    // a new match: the names and how many rounds it goes
+                    for (i, name) in self.hot_seat_window.names.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Player {}:", i + 1));
+                            ui.text_edit_singleline(name);
+                        });
+                    }
+                    ui.horizontal(|ui| {
+                        ui.radio_value(&mut self.hot_seat_window.best_of, None, "Open");
+                        for rounds in [3, 5, 7] {
+                            ui.radio_value(
+                                &mut self.hot_seat_window.best_of,
+                                Some(rounds),
+                                format!("Best of {}", rounds),
+                            );
+                        }
+                    });
+                    if ui.button("Start Match").clicked() {
+                        self.hot_seat = Some(HotSeatMatch::new(
+                            self.hot_seat_window.names.clone(),
+                            self.hot_seat_window.best_of,
+                        ));
+                        self.hot_seat_window.error = None;
+                    }
+                    return;
+                };
+
+                if let Some(best_of) = hot_seat.best_of {
+                    ui.label(format!("Best of {}", best_of));
+                }
+                Self::show_scoreboard(ui, hot_seat);
+                ui.separator();
+
+                if let Some(winner) = hot_seat.winner() {
+                    ui.label(
+                        RichText::new(format!("{} wins the match!", hot_seat.players[winner].name))
+                            .strong(),
+                    );
+                    if ui.button("New Match").clicked() {
+                        self.hot_seat = None;
+                    }
+                } else if round_running {
+                    ui.label(format!(
+                        "{} is guessing the word {} set",
+                        hot_seat.players[hot_seat.guesser()].name,
+                        hot_seat.players[hot_seat.setter].name
+                    ));
+                } else {
+                    // the guesser looks away while the setter types, the word is hidden
+                    ui.label(format!(
+                        "{}, enter a word for {} to guess:",
+                        hot_seat.players[hot_seat.setter].name,
+                        hot_seat.players[hot_seat.guesser()].name
+                    ));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.hot_seat_window.word).password(true),
+                    );
+                    if ui.button("Set Word").clicked() {
+                        self.hot_seat_window.error = self.start_hot_seat_round().err();
+                    }
+                    if ui.button("End Match").clicked() {
+                        self.hot_seat = None;
+                    }
+                }
+
+                if let Some(e) = &self.hot_seat_window.error {
+                    ui.label(e);
+                }
+            });
+        self.hot_seat_window.show = open;
+    }
+
+    /// Method that starts a marathon with a random word
+    fn start_marathon(&mut self) {
+        let Some(word) = self.pick_word() else {
+            return;
+        };
+
+        let run = MarathonRun::default();
+        self.start_game(word, self.difficulty, run.attempts);
+        self.marathon = Some(run);
+        self.marathon_place = None;
+        self.mode = GameMode::Marathon;
+    }
+
+    /// Method that ends a marathon and puts it in the best-run table if it is one of the best
+    fn finish_marathon(&mut self) {
+        let Some(run) = &self.marathon else {
+            return;
+        };
+
+        let record = MarathonRecord {
+            solved: run.score(),
+            word_length: self.language.word_length,
+            date: daily::date_string(daily::today()),
+        };
+        match self.marathon_table.record(self.language, record) {
+            Ok(place) => self.marathon_place = place,
+            Err(e) => println!("Error saving your best marathons: {}", e),
+        }
+    }
+
+    /// Helper function that shows how a marathon is going, or how it went once it is over
+    fn show_marathon_status(&self, ui: &mut egui::Ui) {
+        let Some(run) = &self.marathon else {
+            return;
+        };
+
+        if !run.is_over() {
+            ui.label(
+                RichText::new(format!(
+                    "Marathon: {} solved, {} attempts for this word",
+                    run.score(),
+                    run.attempts
+                ))
+                .size(20.0),
+            );
+            return;
+        }
+
+        ui.label(RichText::new(format!("Marathon over: {} words solved", run.score())).strong());
+        if let Some(place) = self.marathon_place {
+            ui.label(format!("That's #{} in your best runs!", place));
+        }
+        ui.add_space(10.0);
+    }
+
+    /// Function for the table of the best marathons
+    fn show_marathon_table_window(&mut self, ctx: &egui::Context) {
+        egui::Window::new("Best Marathons")
+            .open(&mut self.show_marathon_table)
+            .show(ctx, |ui| {
+                if self.marathon_table.runs.is_empty() {
+                    ui.label("No marathons yet.");
+                }
+                egui::Grid::new("marathon_table")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (place, run) in self.marathon_table.runs.iter().enumerate() {
+                            ui.label(format!("#{}", place + 1));
+                            ui.label(format!("{} words", run.solved));
+                            ui.label(format!("{} letters", run.word_length));
+                            ui.label(&run.date);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// Method that stops the clock of a timed run and keeps its score if it is a personal best
+    fn finish_timed_run(&mut self) {
+        let Some(run) = &mut self.timed_run else {
+            return;
+        };
+        run.finish();
+        match self.personal_bests.record(run) {
+            Ok(new_best) => self.new_best = new_best,
+            Err(e) => println!("Error saving your personal bests: {}", e),
+        }
+    }
+
+    /// Function that keeps the clock of a timed run going, the window is repainted every frame while it runs
+    fn update_timer(&mut self, ctx: &egui::Context) {
+        let Some(run) = &self.timed_run else {
+            return;
+        };
+        if run.finished.is_some() {
+            return;
+        }
+        if run.is_out_of_time() {
+            self.finish_timed_run();
+            return;
+        }
+        ctx.request_repaint();
+    }
+
+    /// Helper function that shows the clock of a running timed run
+    fn show_timer(&self, ui: &mut egui::Ui) {
+        let Some(run) = self.timed_run.as_ref().filter(|run| run.finished.is_none()) else {
+            return;
+        };
+
+        match run.kind {
+            TimerKind::Countdown { .. } => ui.label(
+                RichText::new(format!(
+                    "Time left {}, solved {}",
+                    timed::clock(run.remaining().unwrap_or_default()),
+                    run.solved()
+                ))
+                .size(20.0),
+            ),
+            TimerKind::Stopwatch => ui.label(
+                RichText::new(format!(
+                    "Time {} + {} s for {} guesses",
+                    timed::clock(run.elapsed()),
+                    self.game_logic.current_row as u64 * timed::PENALTY_PER_GUESS,
+                    self.game_logic.current_row
+                ))
+                .size(20.0),
+            ),
+        };
+    }
+
+    /// Helper function for a score as it is shown, words for a countdown and seconds for a speed run
+    fn format_score(run: &TimedRun, score: f64) -> String {
+        match run.kind {
+            TimerKind::Countdown { .. } => format!("{} words", score),
+            TimerKind::Stopwatch => format!("{:.1} s", score),
+        }
+    }
+
+    /// Function for the results of a finished timed run, with the personal best of its mode and word length
+    fn show_results_window(&mut self, ctx: &egui::Context) {
+        let Some(run) = self.timed_run.as_ref().filter(|run| run.finished.is_some()) else {
+            return;
+        };
+        let mut play_again = false;
+        let mut close = false;
+
+        egui::Window::new("Results")
+            .collapsible(false)
+            .show(ctx, |ui| {
+                match run.kind {
+                    TimerKind::Countdown { minutes } => {
+                        ui.heading(format!("{} minute run", minutes));
+                        ui.label(format!("Solved {} words", run.solved()));
+                    }
+                    TimerKind::Stopwatch => {
+                        ui.heading("Speed run");
+                        match run.score() {
+                            Some(score) => ui.label(format!(
+                                "Time {} + {} s penalty = {:.1} s",
+                                timed::clock(run.elapsed()),
+                                run.penalty().as_secs(),
+                                score
+                            )),
+                            None => ui.label("Not solved"),
+                        };
+                    }
+                }
+
+                for word in &run.words {
+                    let result = if word.won {
+                        format!("{} guesses", word.guesses)
+                    } else {
+                        "missed".to_string()
+                    };
+                    ui.label(format!("{}: {}", self.language.visual(&word.word), result));
+                }
+
+                ui.separator();
+                if self.new_best {
+                    ui.label(RichText::new("New personal best!").strong());
+                } else if let Some(&best) = self.personal_bests.bests.get(&run.best_key()) {
+                    ui.label(format!("Personal best: {}", Self::format_score(run, best)));
+                }
+
+                ui.horizontal(|ui| {
+                    play_again = ui.button("Play again").clicked();
+                    close = ui.button("Close").clicked();
+                });
+            });
+
+        if play_again {
+            let kind = run.kind;
+            self.start_timed_run(kind);
+        } else if close {
+            self.timed_run = None;
+        }
+    }
+
+    /// Method that starts a game the player asked for, a game that is still going is only left once the player confirms
+    fn request_new_game(&mut self, game: NewGame) {
+        if self.running_session().is_some() {
+            self.pending_game = Some(game);
+        } else {
+            self.play_new_game(game);
+        }
+    }
+
+    /// Helper function that starts a game the player asked for
+    fn play_new_game(&mut self, game: NewGame) {
+        match game {
+            NewGame::Random => self.new_game(),
+            NewGame::Daily(day) => self.start_daily(day),
+            NewGame::Absurdle => self.start_absurdle(),
+            NewGame::Timed(kind) => self.start_timed_run(kind),
+            NewGame::Marathon => self.start_marathon(),
+            NewGame::Mastermind(symbols) => self.start_mastermind(symbols),
+            NewGame::MultiBoard(boards) => self.start_multi_board(boards),
+        }
+    }
+
+    /// Helper function that tells what a new game would end, None if nothing is going on
+    fn running_session(&self) -> Option<String> {
+        if let Some(run) = self.timed_run.as_ref().filter(|run| run.finished.is_none()) {
+            let kind = match run.kind {
+                TimerKind::Countdown { minutes } => format!("The {} minute run", minutes),
+                TimerKind::Stopwatch => "The speed run".to_string(),
+            };
+            return Some(format!(
+                "{} is still going, a new game ends it with the words solved so far.",
+                kind
+            ));
+        }
+        if let Some(hot_seat) = self.hot_seat.as_ref().filter(|_| {
+            self.mode == GameMode::HotSeat
+                && !matches!(self.game_state, Some(GameState::Won | GameState::Lost))
+        }) {
+            return Some(format!(
+                "{} is still guessing, a new game throws this round away. The match goes on with the next word {} sets.",
+                hot_seat.players[hot_seat.guesser()].name,
+                hot_seat.players[hot_seat.setter].name
+            ));
+        }
+        if let Some(run) = self.marathon.as_ref().filter(|run| !run.is_over()) {
+            return Some(format!(
+                "The marathon is still going, a new game ends it with {} words solved.",
+                run.score()
+            ));
+        }
+        None
+    }
+
+    /// Helper function to check if the cheat may print the answer.
+    /// It is off while a hot-seat round or a daily puzzle is being guessed and while a timed run is going.
+    fn cheat_allowed(&self) -> bool {
+        let guessing = !matches!(self.game_state, Some(GameState::Won | GameState::Lost));
+        let timed_run = self
+            .timed_run
+            .as_ref()
+            .is_some_and(|run| run.finished.is_none());
+        match self.mode {
+            GameMode::HotSeat | GameMode::Daily { .. } => !guessing,
+            _ => !timed_run,
+        }
+    }
+
+    /// Function for the window that asks before a new game ends the one that is going on
+    fn show_confirm_window(&mut self, ctx: &egui::Context) {
+        let Some(game) = self.pending_game else {
+            return;
+        };
+        let Some(message) = self.running_session() else {
+            // the run ended meanwhile, its results are shown instead
+            self.pending_game = None;
+            return;
+        };
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        egui::Window::new("New Game?")
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(message);
+                ui.horizontal(|ui| {
+                    confirmed = ui.button("New Game").clicked();
+                    cancelled = ui.button("Keep Playing").clicked();
+                });
+            });
+
+        if confirmed {
+            self.pending_game = None;
+            self.play_new_game(game);
+        } else if cancelled {
+            self.pending_game = None;
+        }
+    }
+
+    /// Method that puts a new game on the board.
+    /// A timed run or marathon that is left before it is over ends here, with what was played so far.
+    fn reset_board(&mut self, game_logic: MultiBoardGame, difficulty: Difficulty) {
+        if self
+            .timed_run
+            .as_ref()
+            .is_some_and(|run| run.finished.is_none())
+        {
+            self.finish_timed_run();
+        }
+        if self.marathon.as_ref().is_some_and(|run| !run.is_over()) {
+            self.finish_marathon();
+        }
+        self.word = game_logic.answer().cloned().unwrap_or_default();
+        self.definitions.clear();
+        self.timed_run = None;
+        self.marathon = None;
+        self.reverse = None;
+        self.reverse_message = None;
+        self.game_logic = game_logic;
+        self.current_row = 0;
+        self.index = 0;
+        self.game_state = None;
+        self.word_difficulty = difficulty;
+        self.word_denied = false;
+        self.mode = GameMode::Random;
+    }
+
+    /// Method that starts the daily puzzle of a day, today's or a past day's from the archive.
+    /// The daily answer can be any word of the schedule, so it counts as Normal difficulty.
+    /// A date that was already played shows its result instead of starting again,
+    /// a date that was left unfinished goes on with the guesses made so far.
+    fn start_daily(&mut self, day: u64) {
+        let Some(word) = self.daily_schedule.word(day).cloned() else {
+            println!("The dictionary is empty.");
+            return;
+        };
+
+        self.start_game(word, Difficulty::Normal, DEFAULT_ATTEMPTS);
+        self.mode = GameMode::Daily {
+            day,
+            archive: day < daily::today(),
+        };
+        if let Some(result) = self.daily_results.get(day) {
+            self.game_state = Some(if result.won {
+                GameState::Won
+            } else {
+                GameState::Lost
+            });
+            self.load_definitions();
+        } else if let Some(guesses) = self.daily_results.progress(day).cloned() {
+            for guess in guesses {
+                self.game_logic.current_guess = guess;
+                self.submit_guess();
+            }
+            // a guess the dictionary doesn't accept anymore is not left on the input row
+            self.game_logic.current_guess = vec![String::new(); self.game_logic.word_length];
+            self.index = 0;
+        }
+    }
+
+    /// Function for the archive of daily puzzles: every past date with its result, newest first.
+    /// Clicking a date plays it, or shows its result if it was played already.
+    fn show_archive_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_archive;
+        let mut picked = None;
+        let today = daily::today();
+
+        egui::Window::new("Daily Archive")
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "Streak: {} (best {}), archive games don't count",
+                    self.daily_results.current_streak(today),
+                    self.daily_results.best_streak(today)
+                ));
+                ui.separator();
+
+                let days = (today - daily::FIRST_DAY.min(today) + 1) as usize;
+                egui::ScrollArea::vertical().show_rows(ui, 18.0, days, |ui, rows| {
+                    for row in rows {
+                        let day = today - row as u64;
+                        let status = match self.daily_results.get(day) {
+                            Some(result) if result.won => {
+                                format!("won {}/{}", result.guesses, DEFAULT_ATTEMPTS)
+                            }
+                            Some(_) => format!("lost X/{}", DEFAULT_ATTEMPTS),
+                            None => match self.daily_results.progress(day) {
+                                Some(guesses) => {
+                                    format!("started {}/{}", guesses.len(), DEFAULT_ATTEMPTS)
+                                }
+                                None => "unplayed".to_string(),
+                            },
+                        };
+
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "#{} {}: {}",
+                                daily::puzzle_number(day),
+                                daily::date_string(day),
+                                status
+                            ));
+                            let action = if self.daily_results.get(day).is_some() {
+                                "Show"
+                            } else if self.daily_results.progress(day).is_some() {
+                                "Continue"
+                            } else {
+                                "Play"
+                            };
+                            if ui.button(action).clicked() {
+                                picked = Some(day);
+                            }
+                        });
+                    }
+                });
+            });
+
+        if let Some(day) = picked {
+            self.request_new_game(NewGame::Daily(day));
+            open = false;
+        }
+        self.show_archive = open;
+    }
+
+    /// Helper function that checks a word a player entered for someone else to guess.
+    /// The word has to be in the dictionary, so it has the length of the word list.
+    fn check_word(&self, word: &str) -> Result<String, String> {
+        self.check_word_length(word, self.language.word_length)
+    }
+
+    /// Helper function that checks a word a player entered for someone else to guess at the given length
+    fn check_word_length(&self, word: &str, length: usize) -> Result<String, String> {
+        let word = word.trim().to_lowercase();
+        if self.language.tiles(&word).len() != length {
+            return Err(format!("The word needs {} letters", length));
+        }
+        if !self.accepted_guesses.contains(&self.language.key(&word)) {
+            return Err(format!("{} is not in the dictionary", word));
+        }
+        Ok(word)
+    }
+
+    /// Method that makes a code for the word entered in the create puzzle window
+    fn create_puzzle(&self) -> Result<String, String> {
+        let word = self.check_word_length(&self.puzzle_windows.word, self.puzzle_windows.length)?;
+
+        let puzzle = PuzzleCode {
+            language: self.language.code.to_string(),
+            word_length: self.puzzle_windows.length,
+            attempts: self.puzzle_windows.attempts,
+            word,
+        };
+        Ok(puzzle.encode())
+    }
+
+    /// Method that starts the puzzle of a code a friend shared, the board has the length of the puzzle.
+    /// The word has to be made of letters of the language and have the puzzle's length, a code made by hand can hold anything.
+    fn play_puzzle_code(&mut self, code: &str) -> Result<(), String> {
+        let puzzle = PuzzleCode::decode(code).map_err(|e| e.to_string())?;
+
+        if puzzle.language != self.language.code {
+            let name = Language::from_code(&puzzle.language)
+                .map(|language| language.name)
+                .unwrap_or(puzzle.language.as_str());
+            return Err(format!(
+                "This puzzle is in {}, start the game with --lang {}",
+                name, puzzle.language
+            ));
+        }
+        let tiles = self.language.tiles(&puzzle.word);
+        if !tiles.iter().all(|tile| self.language.is_letter(tile)) {
+            return Err("This puzzle's word has letters the language doesn't have".to_string());
+        }
+        if tiles.len() != puzzle.word_length {
+            return Err("This puzzle's word doesn't have the length of the puzzle".to_string());
+        }
+        if !(MIN_WORD_LENGTH..=self.language.word_length).contains(&puzzle.word_length) {
+            return Err(format!(
+                "This puzzle needs a word list with {}-letter words",
+                puzzle.word_length
+            ));
+        }
+        if !(1..=MAX_ATTEMPTS).contains(&puzzle.attempts) {
+            return Err(format!(
+                "This puzzle has {} attempts, at most {} are possible",
+                puzzle.attempts, MAX_ATTEMPTS
+            ));
+        }
+
+        self.start_game(puzzle.word, Difficulty::Normal, puzzle.attempts);
+        self.mode = GameMode::Custom;
+        Ok(())
+    }
+
+    /// Function for the windows to create a puzzle for a friend and to play a code from one
+    fn show_puzzle_windows(&mut self, ctx: &egui::Context) {
+        let mut show_create = self.puzzle_windows.show_create;
+        egui::Window::new("Create Puzzle")
+            .open(&mut show_create)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Word:");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.puzzle_windows.word).password(true),
+                    );
+                });
+                ui.add(
+                    egui::Slider::new(
+                        &mut self.puzzle_windows.length,
+                        MIN_WORD_LENGTH..=self.language.word_length,
+                    )
+                    .text("letters"),
+                );
+                ui.add(
+                    egui::Slider::new(&mut self.puzzle_windows.attempts, 1..=MAX_ATTEMPTS)
+                        .text("attempts"),
+                );
+
+                if ui.button("Create").clicked() {
+                    self.puzzle_windows.created = Some(self.create_puzzle());
+                }
+                match &self.puzzle_windows.created {
+                    Some(Ok(code)) => {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(code).monospace().strong());
+                            if ui.button("Copy").clicked() {
+                                ui.output_mut(|o| o.copied_text = code.clone());
+                            }
+                        });
+                        ui.label("Share this code, a friend plays it with \"Play Code\".");
+                    }
+                    Some(Err(e)) => {
+                        ui.label(e);
+                    }
+                    None => {}
+                }
+            });
+        self.puzzle_windows.show_create = show_create;
+
+        let mut show_play = self.puzzle_windows.show_play;
+        let mut started = false;
+        egui::Window::new("Play Code")
+            .open(&mut show_play)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Code:");
+                    ui.text_edit_singleline(&mut self.puzzle_windows.code);
+                });
+                if ui.button("Play").clicked() {
+                    let code = self.puzzle_windows.code.clone();
+                    match self.play_puzzle_code(&code) {
+                        Ok(()) => {
+                            self.puzzle_windows.error = None;
+                            started = true;
+                        }
+                        Err(e) => self.puzzle_windows.error = Some(e),
+                    }
+                }
+                if let Some(e) = &self.puzzle_windows.error {
+                    ui.label(e);
+                }
+            });
+        self.puzzle_windows.show_play = show_play && !started;
+    }
+
+    /// Helper function for the accepted guesses of the word length split into tiles, the words reverse mode is played with
+    fn reverse_words(&self) -> Vec<Vec<String>> {
+        self.accepted_guesses
+            .iter()
+            .map(|key| self.language.tiles(key))
+            .filter(|tiles| tiles.len() == self.language.word_length)
+            .collect()
+    }
+
+    /// Method that starts a reverse game, the hint of every row is counted once up front
+    fn start_reverse(&mut self, game: ReverseGame, words: &[Vec<String>]) {
+        let hints = (0..game.targets.len())
+            .map(|row| game.solutions(row, words))
+            .collect();
+
+        self.start_game(
+            game.answer.word.clone(),
+            Difficulty::Normal,
+            game.targets.len(),
+        );
+        self.reverse = Some(game);
+        self.reverse_hints = hints;
+        self.mode = GameMode::Reverse;
+    }
+
+    /// Method that starts a reverse game with a random answer, the rows are the colors of a few random words
+    fn start_random_reverse(&mut self) {
+        let Some(word) = self.pick_word() else {
+            return;
+        };
+
+        let words = self.reverse_words();
+        let answer = self.language.tiles(&word);
+        let mut targets: Vec<Vec<LetterState>> = words
+            .choose_multiple(&mut rand::thread_rng(), REVERSE_ROWS)
+            .map(|tiles| score_guess(tiles, &answer))
+            .collect();
+        targets.push(vec![LetterState::Correct; answer.len()]);
+
+        let game = ReverseGame::new(word, self.language, targets);
+        self.start_reverse(game, &words);
+    }
+
+    /// Method that starts the grid of a share string a friend sent.
+    /// A daily grid is played against the answer of that daily puzzle, once the player has played it themselves
+    /// so the grid doesn't give away an answer they haven't found yet.
+    /// Any other grid gets a random answer for which every row can be matched.
+    fn play_share(&mut self, text: &str) -> Result<(), String> {
+        let grid = parse_share_string(text).map_err(|e| e.to_string())?;
+        let word_length = grid.rows[0].len();
+        if word_length != self.language.word_length {
+            return Err(format!("This grid is for {}-letter words", word_length));
+        }
+        if grid.rows.len() > MAX_ATTEMPTS {
+            return Err(format!(
+                "This grid has {} rows, at most {} are possible",
+                grid.rows.len(),
+                MAX_ATTEMPTS
+            ));
+        }
+
+        let words = self.reverse_words();
+        let game = match grid.daily_number {
+            Some(number) => {
+                let day = daily::puzzle_day(number)
+                    .ok_or(format!("There is no daily puzzle #{}", number))?;
+                if self.daily_results.get(day).is_none() {
+                    return Err(format!(
+                        "Play daily puzzle #{} first, its grid would give the answer away",
+                        number
+                    ));
+                }
+                let word = self
+                    .daily_schedule
+                    .word(day)
+                    .cloned()
+                    .ok_or("The dictionary is empty.")?;
+                ReverseGame::new(word, self.language, grid.rows)
+            }
+            None => (0..REVERSE_TRIES)
+                .filter_map(|_| self.pick_word())
+                .map(|word| ReverseGame::new(word, self.language, grid.rows.clone()))
+                .find(|game| game.is_solvable(&words))
+                .ok_or("No answer was found that fits every row of this grid")?,
+        };
+        self.start_reverse(game, &words);
+        Ok(())
+    }
+
+    /// Function to check the current guess against the colors of the current row of the reverse game
+    fn submit_reverse_guess(&mut self) {
+        let Some(game) = &mut self.reverse else {
+            return;
+        };
+
+        let guess = self.game_logic.current_guess.clone();
+        match game.submit_guess(&guess, self.accepted_guesses.as_ref()) {
+            ReverseResult::Incomplete | ReverseResult::NotAWord => {
+                self.game_state = Some(GameState::WrongGuess);
+                self.last_guessed_word = Some(self.language.display(&guess));
+                self.reverse_message = None;
+            }
+            ReverseResult::WrongPattern(pattern) => {
+                self.game_state = None;
+                self.reverse_message = Some(format!(
+                    "{} gives {}",
+                    self.language.visual(&self.language.display(&guess)),
+                    pattern_squares(&pattern)
+                ));
+            }
+            ReverseResult::Match => {
+                self.game_state = Some(GameState::CorrectGuess);
+                self.reverse_message = None;
+                self.game_logic.current_guess = vec![String::new(); self.game_logic.word_length];
+                self.index = 0;
+            }
+            ReverseResult::Complete => {
+                self.game_state = Some(GameState::Won);
+                self.reverse_message = None;
+                self.load_definitions();
+            }
         }
     }
 
-    fn new_game(&mut self) {
-        let (new_word, new_definition) =
-            match pick_random_word(self.dictionary.as_ref(), WORLD_LENGTH) {
-                Ok((word, defintion)) => (word, defintion),
+    /// Function for the window to play the grid of a share string in reverse mode, or a random grid
+    fn show_reverse_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.reverse_window.show;
+        let mut started = false;
+        egui::Window::new("Reverse Puzzle")
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label("Paste the result a friend shared:");
+                ui.text_edit_multiline(&mut self.reverse_window.text);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Play").clicked() {
+                        let text = self.reverse_window.text.clone();
+                        match self.play_share(&text) {
+                            Ok(()) => {
+                                self.reverse_window.error = None;
+                                started = true;
+                            }
+                            Err(e) => self.reverse_window.error = Some(e),
+                        }
+                    }
+                    if ui.button("Random Puzzle").clicked() {
+                        self.start_random_reverse();
+                        self.reverse_window.error = None;
+                        started = true;
+                    }
+                });
+                if let Some(e) = &self.reverse_window.error {
+                    ui.label(e);
+                }
+            });
+        self.reverse_window.show = open && !started;
+    }
+
+    /// Method that reads the definitions of the answers from disk, only done once they are shown
+    fn load_definitions(&mut self) {
+        self.definitions = self
+            .game_logic
+            .answers()
+            .into_iter()
+            .map(|word| match self.dictionary.definition(word) {
+                Ok(definition) => (word.clone(), definition),
                 Err(e) => {
-                    println!("Error: {}", e);
-                    return;
+                    println!("Error reading the definition of {}: {}", word, e);
+                    (word.clone(), Definition::default())
                 }
+            })
+            .collect();
+    }
+
+    /// Helper function for the answers of the boards as they are shown, e.g. "CRANE, STONE"
+    fn answers_text(&self) -> String {
+        let answers: Vec<String> = self
+            .game_logic
+            .answers()
+            .into_iter()
+            .map(|word| self.language.visual(word))
+            .collect();
+        answers.join(", ")
+    }
+
+    /// Helper function that shows the daily puzzle's number, its result and the time until the next one
+    fn show_daily_status(&self, ui: &mut egui::Ui, day: u64, archive: bool) {
+        ui.label(format!(
+            "Daily puzzle #{} ({}){}",
+            daily::puzzle_number(day),
+            daily::date_string(day),
+            if archive { " from the archive" } else { "" }
+        ));
+        if let Some(result) = self.daily_results.get(day) {
+            if result.won {
+                ui.label(format!("Solved in {}/{}", result.guesses, DEFAULT_ATTEMPTS));
+            } else {
+                ui.label(format!("Not solved, X/{}", DEFAULT_ATTEMPTS));
+            }
+        }
+
+        let today = daily::today();
+        ui.label(format!(
+            "Streak: {} (best {})",
+            self.daily_results.current_streak(today),
+            self.daily_results.best_streak(today)
+        ));
+
+        // the countdown ticks every second, so the window is repainted even without input
+        ui.label(format!(
+            "Next puzzle in {}",
+            daily::countdown(daily::seconds_until_next())
+        ));
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_secs(1));
+    }
+
+    /// Method that adds the rejected guess to the player's allowed words and submits it again
+    fn add_to_my_words(&mut self) {
+        // letters that make other tiles (I and J for the Dutch IJ) can't be added as they are
+        let Some(key) = self.language.guess_key(&self.game_logic.current_guess) else {
+            println!("These tiles don't spell a word of the language");
+            return;
+        };
+        let word = self.language.display(&self.game_logic.current_guess);
+        if let Err(e) = allow_word(self.language, &word) {
+            println!("Error saving your personal words: {}", e);
+            return;
+        }
+
+        Arc::make_mut(&mut self.accepted_guesses).insert(key);
+        self.submit_guess();
+    }
+
+    /// Method that adds the current answer to the player's denied words, so it is never picked again
+    fn deny_current_word(&mut self) {
+        if let Err(e) = deny_word(self.language, &self.word) {
+            println!("Error saving your personal words: {}", e);
+            return;
+        }
+
+        Arc::make_mut(&mut self.answers).remove(&self.word);
+        self.word_denied = true;
+    }
+
+    /// Helper function that shows the definitions as a list of their senses, every sense can be collapsed
+    /// With several boards every answer gets its own heading
+    fn show_definition(&self, ui: &mut egui::Ui) {
+        for (word, definition) in &self.definitions {
+            if self.definitions.len() > 1 {
+                ui.label(RichText::new(self.language.visual(word)).strong());
+            }
+            if definition.is_empty() {
+                ui.label("There is no definition for this word.");
+                continue;
+            }
+            self.show_senses(ui, word, definition);
+        }
+    }
+
+    /// Helper function that shows the senses of a definition
+    fn show_senses(&self, ui: &mut egui::Ui, word: &str, definition: &Definition) {
+        for (i, sense) in definition.senses.iter().enumerate() {
+            egui::CollapsingHeader::new(sense.title())
+                .id_source((word, i))
+                .default_open(i == 0)
+                .show(ui, |ui| {
+                    if !sense.labels.is_empty() {
+                        ui.label(RichText::new(sense.labels.join(", ")).italics().weak());
+                    }
+                    if !sense.text.is_empty() {
+                        ui.label(&sense.text);
+                    }
+                    for note in &sense.notes {
+                        ui.label(RichText::new(format!("Note: {}", note)).small());
+                    }
+                    if !sense.see_also.is_empty() {
+                        ui.label(format!("See also: {}", sense.see_also.join(", ")));
+                    }
+                });
+        }
+    }
+
+    /// Helper function for the button under a finished game that copies its result as a share string
+    fn share_button(&self, ui: &mut egui::Ui) {
+        let board = &self.game_logic.boards[0];
+        // a daily puzzle that was played before only has its result, not its rows
+        if board.guesses.is_empty() {
+            return;
+        }
+
+        if ui.button("Copy result").clicked() {
+            let daily_number = match self.mode {
+                GameMode::Daily { day, .. } => Some(daily::puzzle_number(day)),
+                _ => None,
             };
+            let text = share_string(
+                daily_number,
+                &board.guesses,
+                self.game_state == Some(GameState::Won),
+                board.max_attempts,
+            );
+            ui.output_mut(|o| o.copied_text = text);
+        }
+    }
 
-        self.word = new_word.clone();
-        self.definition = new_definition;
-        self.game_logic = WordleGame::new(new_word);
-        self.grid = [[' '; 5]; 6];
-        self.current_row = 0;
-        self.index = 0;
-        self.game_state = None;
+    /// Helper function for the button under a finished game that keeps its word out of the answers
+    fn deny_button(&mut self, ui: &mut egui::Ui) {
+        if self.word_denied {
+            ui.label("This word won't be picked as an answer again.");
+        } else if ui.button("Never pick this as an answer").clicked() {
+            self.deny_current_word();
+        }
+    }
+
+    /// Function to submit the current guess and update the game accordingly.
+    /// Finished games are recorded in the game history together with their difficulty.
+    fn submit_guess(&mut self) {
+        // the game is already over
+        if let Some(GameState::Won | GameState::Lost) = self.game_state {
+            return;
+        }
+        // the clock of the timed run is up
+        if self
+            .timed_run
+            .as_ref()
+            .is_some_and(|run| run.finished.is_some())
+        {
+            return;
+        }
+        // reverse mode checks the guess against the colors of a row instead
+        if self.reverse.is_some() {
+            self.submit_reverse_guess();
+            return;
+        }
+
+        match self.game_logic.submit_guess(self.accepted_guesses.as_ref()) {
+            (GameState::CorrectGuess, _) => {
+                self.current_row += 1;
+                self.index = 0;
+                self.game_state = Some(GameState::CorrectGuess);
+
+                // the daily puzzle is saved after every guess, so leaving it doesn't start it over
+                if let GameMode::Daily { day, .. } = self.mode {
+                    let guesses = self.game_logic.boards[0].guesses_letters.clone();
+                    if let Err(e) = self
+                        .daily_results
+                        .save_progress(self.language, day, guesses)
+                    {
+                        println!("Error saving your daily puzzle: {}", e);
+                    }
+                }
+            }
+            (GameState::WrongGuess, word) => {
+                println!("{}", GameState::WrongGuess);
+                self.game_state = Some(GameState::WrongGuess);
+                self.last_guessed_word = word;
+            }
+            (state @ (GameState::Won | GameState::Lost), _) => {
+                self.game_state = Some(state);
+                self.current_row += 1;
+
+                // an Absurdle game settles on its answer only now
+                if let Some(word) = self.game_logic.answer() {
+                    self.word = word.clone();
+                }
+                // a code has no definition and is not a word of the game history
+                if self.mode == GameMode::Mastermind {
+                    return;
+                }
+                self.load_definitions();
+
+                // every date can only be played once
+                if let GameMode::Daily { day, archive } = self.mode {
+                    let result = DailyResult {
+                        word: self.word.clone(),
+                        won: matches!(state, GameState::Won),
+                        guesses: self.game_logic.current_row,
+                    };
+                    if let Err(e) = self
+                        .daily_results
+                        .record(self.language, day, archive, result)
+                    {
+                        println!("Error saving your daily result: {}", e);
+                    }
+                }
+
+                // every board is recorded as a game of its own
+                for (board, solved) in self.game_logic.boards.iter().zip(&self.game_logic.solved) {
+                    let Some(word) = board.answer() else {
+                        continue;
+                    };
+                    let record = GameRecord::new(
+                        word,
+                        self.word_difficulty,
+                        solved.is_some(),
+                        solved.map_or(self.game_logic.current_row, |row| row + 1),
+                    );
+                    if let Err(e) = record_game(&record) {
+                        println!("Error recording game: {}", e);
+                    }
+                }
+
+                // a countdown goes on with the next word until the time is up, a speed run ends with its word
+                if let Some(run) = &mut self.timed_run {
+                    run.words.push(TimedWord {
+                        word: self.word.clone(),
+                        won: matches!(state, GameState::Won),
+                        guesses: self.game_logic.current_row,
+                    });
+                    match run.kind {
+                        TimerKind::Countdown { .. } => self.next_session_word(DEFAULT_ATTEMPTS),
+                        TimerKind::Stopwatch => self.finish_timed_run(),
+                    }
+                }
+
+                // a marathon goes on from its pool of attempts until a word isn't solved
+                if let Some(run) = &mut self.marathon {
+                    if state == GameState::Won {
+                        run.solve(&self.word, self.game_logic.current_row);
+                        let attempts = run.attempts;
+                        self.next_session_word(attempts);
+                    } else {
+                        run.fail(&self.word);
+                        self.finish_marathon();
+                    }
+                }
+
+                // a two-player round is scored and the roles swap, the next setter enters a word in the window
+                if let Some(hot_seat) = self
+                    .hot_seat
+                    .as_mut()
+                    .filter(|_| self.mode == GameMode::HotSeat)
+                {
+                    hot_seat.record(state == GameState::Won, self.game_logic.current_row);
+                    self.hot_seat_window.show = true;
+                }
+            }
+        }
     }
 
     /// Run method for the app
-    /// This method takes in the dictionary and picks a random word to guess
-    pub fn run(dict: &HashMap<String, String>) {
-        let dictionary_clone = Arc::new(dict.clone());
+    /// This method takes in the dictionary, the answers and the accepted guesses of the language and picks a random word to guess
+    /// With daily set, the game starts with today's daily puzzle from the schedule
+    pub fn run(
+        dict: DefinitionStore,
+        answers: AnswerPool,
+        accepted_guesses: HashSet<String>,
+        daily_schedule: DailySchedule,
+        daily: bool,
+        language: &'static Language,
+    ) {
+        let dictionary = Arc::new(dict);
+        let accepted_guesses = Arc::new(accepted_guesses);
+        let daily_schedule = Arc::new(daily_schedule);
 
         let native_options = eframe::NativeOptions {
             viewport: ViewportBuilder {
@@ -83,8 +1518,8 @@ impl MyEguiApp {
             ..Default::default()
         };
 
-        let (word, definition) = match pick_random_word(&dict, WORLD_LENGTH) {
-            Ok((word, definition)) => (word, definition),
+        let word = match answers.pick(Difficulty::Normal) {
+            Ok(word) => word,
             Err(e) => {
                 println!("{}", e);
                 return;
@@ -94,217 +1529,361 @@ impl MyEguiApp {
         let _ = eframe::run_native(
             "RustyWordle",
             native_options,
-            Box::new(|cc| {
-                Ok(Box::new(MyEguiApp::new(
+            Box::new(move |cc| {
+                let mut app = MyEguiApp::new(
                     cc,
                     word,
-                    definition,
-                    dictionary_clone,
-                )))
+                    dictionary,
+                    Arc::new(answers),
+                    accepted_guesses,
+                    daily_schedule,
+                    language,
+                );
+                if daily {
+                    app.start_daily(daily::today());
+                }
+                Ok(Box::new(app))
             }),
         );
     }
 
-    /// Helper function to associate key presses with char and actions
+    /// Helper function to associate key presses with actions
+    /// Letters are not handled here, they arrive as text so letters without a key of their own (Ä, Ñ) work as well
     fn key_to_char(&self, key: &egui::Key) -> Option<char> {
         match key {
-            egui::Key::A => Some('A'),
-            egui::Key::B => Some('B'),
-            egui::Key::C => Some('C'),
-            egui::Key::D => Some('D'),
-            egui::Key::E => Some('E'),
-            egui::Key::F => Some('F'),
-            egui::Key::G => Some('G'),
-            egui::Key::H => Some('H'),
-            egui::Key::I => Some('I'),
-            egui::Key::J => Some('J'),
-            egui::Key::K => Some('K'),
-            egui::Key::L => Some('L'),
-            egui::Key::M => Some('M'),
-            egui::Key::N => Some('N'),
-            egui::Key::O => Some('O'),
-            egui::Key::P => Some('P'),
-            egui::Key::Q => Some('Q'),
-            egui::Key::R => Some('R'),
-            egui::Key::S => Some('S'),
-            egui::Key::T => Some('T'),
-            egui::Key::U => Some('U'),
-            egui::Key::V => Some('V'),
-            egui::Key::W => Some('W'),
-            egui::Key::X => Some('X'),
-            egui::Key::Y => Some('Y'),
-            egui::Key::Z => Some('Z'),
             //numeric values to handle enter and backspace
             egui::Key::Enter => Some('1'),
             egui::Key::Backspace => Some('2'),
             egui::Key::Space => Some('3'),
-            //for quick cheating
-            egui::Key::Semicolon => Some('4'),
+            //for quick cheating, unless the keyboard layout of the language types a letter with it (Arabic)
+            egui::Key::Semicolon if !self.language.keymap.iter().any(|(key, _)| *key == ';') => {
+                Some('4')
+            }
             _ => None,
         }
     }
 
+    /// Function to put a tile of the alphabet into the current guess
+    fn insert_tile(&mut self, tile: String) {
+        // a finished game takes no more letters
+        if let Some(GameState::Won | GameState::Lost) = self.game_state {
+            return;
+        }
+        if self.current_row < self.game_logic.max_attempts {
+            self.game_logic.current_guess[self.index] = tile;
+            if self.index < self.game_logic.word_length - 1 {
+                self.index += 1;
+            }
+        }
+    }
+
+    /// Function to handle a typed letter.
+    /// The letter is turned into tiles by the language of the game (Korean syllables become several jamo tiles).
+    /// If the letter completes a digraph of the language together with the previous tile (I + J -> IJ), the two are merged.
+    fn type_letter(&mut self, letter: char) {
+        let symbols = self.game_logic.language;
+        for typed in symbols.typed_tiles(letter) {
+            let previous = if self.game_logic.current_guess[self.index].is_empty() {
+                self.index.checked_sub(1)
+            } else {
+                Some(self.index)
+            };
+            if let Some(previous) = previous {
+                let combined = format!("{}{}", self.game_logic.current_guess[previous], typed);
+                if symbols.digraphs.contains(&combined.as_str()) {
+                    self.game_logic.current_guess[previous] = combined;
+                    continue;
+                }
+            }
+
+            if symbols.is_letter(&typed) {
+                self.insert_tile(typed);
+            }
+        }
+    }
+
     /// Function to handle keyboard inputs for letters
     fn handle_keyboard_input(&mut self, ctx: &egui::Context) {
+        // typing into a text field (the puzzle windows) doesn't go to the grid
+        if ctx.wants_keyboard_input() {
+            return;
+        }
         let input = ctx.input(|i| i.clone());
 
         for event in &input.events {
-            if let egui::Event::Key { key, pressed, .. } = event {
-                if *pressed {
-                    if let Some(letter) = self.key_to_char(key) {
-                        // check for a letter else it is an acction
-                        if letter.is_ascii_alphabetic() && self.current_row < WORLD_LENGTH + 1 {
-                            self.game_logic.current_guess[self.index] = letter.to_ascii_uppercase();
-                            if self.index < WORLD_LENGTH - 1 {
-                                self.index += 1;
-                            }
-                        } else {
-                            let _ = match letter {
-                                // enter -> submit guess
-                                '1' => {
-                                    match self.game_logic.submit_guess(self.dictionary.as_ref()) {
-                                        (GameState::CorrectGuess, _) => {
-                                            self.update_alphabet_state();
-                                            self.current_row += 1;
-                                            self.index = 0;
-                                            self.game_state = Some(GameState::CorrectGuess);
-                                        }
-                                        (GameState::WrongGuess, word) => {
-                                            self.game_state = Some(GameState::WrongGuess);
-                                            self.last_guessed_word = word;
-                                        }
-                                        (GameState::Lost, _) => {
-                                            self.game_state = Some(GameState::Lost);
-                                        }
-                                        (GameState::Won, _) => {
-                                            self.game_state = Some(GameState::Won);
-                                        }
-                                    }
-                                }
-                                // backspace -> delete letter
-                                '2' => {
-                                    // does the square already have a letter?
-                                    if self.game_logic.current_guess[self.index] != ' ' {
-                                        //remove it
-                                        self.game_logic.current_guess[self.index] = ' '
-                                    }
-                                    //take a step back and delete
-                                    else {
-                                        if self.index == 0 {
-                                        } else {
-                                            self.index -= 1;
-                                            self.game_logic.current_guess[self.index] = ' ';
-                                        }
-                                    }
-                                }
-                                // space -> new game
-                                '3' => {
-                                    self.new_game();
+            match event {
+                // text typed directly or committed by an input method (Korean, ...)
+                egui::Event::Text(text) | egui::Event::Ime(egui::ImeEvent::Commit(text)) => {
+                    for letter in text.chars() {
+                        self.type_letter(letter);
+                    }
+                }
+                egui::Event::Key {
+                    key, pressed: true, ..
+                } => {
+                    if let Some(action) = self.key_to_char(key) {
+                        match action {
+                            // enter -> submit guess
+                            '1' => self.submit_guess(),
+                            // backspace -> delete letter
+                            '2' => {
+                                // does the square already have a letter?
+                                if !self.game_logic.current_guess[self.index].is_empty() {
+                                    //remove it
+                                    self.game_logic.current_guess[self.index].clear();
                                 }
-                                // for cheating
-                                '4' => {
-                                    println!("{}", self.word);
+                                //take a step back and delete
+                                else if self.index > 0 {
+                                    self.index -= 1;
+                                    self.game_logic.current_guess[self.index].clear();
                                 }
-                                _ => {}
-                            };
-                        }
+                            }
+                            // space -> new game
+                            '3' => {
+                                self.request_new_game(NewGame::Random);
+                            }
+                            // for cheating
+                            '4' if self.cheat_allowed() => {
+                                println!("{}", self.answers_text());
+                            }
+                            _ => {}
+                        };
                     }
                 }
+                _ => {}
+            }
+        }
+    }
+
+    /// Function that renders the guesses of a board, the input row is shown on every board that isn't solved yet.
+    /// With several boards each one is numbered and marked once it is solved.
+    fn show_board(&self, ui: &mut egui::Ui, index: usize, tile_size: f32) {
+        let board = &self.game_logic.boards[index];
+        let solved = self.game_logic.solved[index];
+        let word_length = board.word_length;
+        let button_size = egui::vec2(tile_size, tile_size);
+
+        if self.game_logic.boards.len() > 1 {
+            match solved {
+                Some(row) => ui.label(
+                    RichText::new(format!("Board {}: solved in {}", index + 1, row + 1))
+                        .color(egui::Color32::GREEN),
+                ),
+                None => ui.label(format!("Board {}", index + 1)),
+            };
+        }
+
+        for row in 0..board.max_attempts {
+            let row_tiles = if row == self.game_logic.current_row && solved.is_none() {
+                Some(&self.game_logic.current_guess)
+            } else {
+                board.guesses_letters.get(row)
+            };
+
+            // right to left languages fill the row from the right, the colors stay with the position in the word
+            let columns: Vec<usize> = if board.language.rtl {
+                (0..word_length).rev().collect()
+            } else {
+                (0..word_length).collect()
+            };
+
+            ui.horizontal(|ui| {
+                for col in columns {
+                    let cell_color = match board.guesses.get(row) {
+                        Some(guess) => match guess[col] {
+                            LetterState::Correct => egui::Color32::GREEN,
+                            LetterState::Present => egui::Color32::YELLOW,
+                            LetterState::Wrong => egui::Color32::from_gray(50),
+                            LetterState::Unknown => egui::Color32::from_gray(50),
+                        },
+                        None => egui::Color32::from_gray(80),
+                    };
+
+                    let letter = match row_tiles {
+                        Some(tiles) => board.language.tile_form(tiles, col),
+                        None => String::new(),
+                    };
+
+                    let text = if letter.is_empty() { "_" } else { &letter };
+                    let text = match cell_color {
+                        egui::Color32::GREEN | egui::Color32::YELLOW => {
+                            RichText::new(text).color(egui::Color32::BLACK)
+                        }
+                        _ => RichText::new(text),
+                    };
+                    let button = egui::Button::new(text)
+                        .min_size(button_size)
+                        .fill(cell_color);
+                    ui.add(button);
+                }
+            });
+
+            // languages that are played in jamo also show the row recomposed into syllables
+            if board.language.tile_scheme == TileScheme::HangulJamo {
+                if let Some(tiles) = row_tiles {
+                    ui.label(RichText::new(board.language.display(tiles)).size(20.0));
+                }
             }
+            ui.add_space(5.0);
+        }
+    }
+
+    /// Function that renders the rows of a reverse game: the colors to match with the words found so far and the input row.
+    /// Every row shows how many words give its colors.
+    fn show_reverse_board(&self, ui: &mut egui::Ui, game: &ReverseGame, tile_size: f32) {
+        let button_size = egui::vec2(tile_size, tile_size);
+        let current_row = game.current_row();
+
+        for (row, target) in game.targets.iter().enumerate() {
+            let row_tiles = if Some(row) == current_row {
+                Some(&self.game_logic.current_guess)
+            } else {
+                game.found[row].as_ref()
+            };
+
+            // right to left languages fill the row from the right, the colors stay with the position in the word
+            let columns: Vec<usize> = if self.language.rtl {
+                (0..target.len()).rev().collect()
+            } else {
+                (0..target.len()).collect()
+            };
+
+            ui.horizontal(|ui| {
+                for col in columns {
+                    let cell_color = key_color(target[col]);
+                    let letter = match row_tiles {
+                        Some(tiles) => self.language.tile_form(tiles, col),
+                        None => String::new(),
+                    };
+
+                    let text = if letter.is_empty() { "_" } else { &letter };
+                    let text = match target[col] {
+                        LetterState::Correct | LetterState::Present => {
+                            RichText::new(text).color(egui::Color32::BLACK)
+                        }
+                        _ => RichText::new(text),
+                    };
+                    let button = egui::Button::new(text)
+                        .min_size(button_size)
+                        .fill(cell_color);
+                    ui.add(button);
+                }
+                if let Some(hint) = self.reverse_hints.get(row) {
+                    ui.label(RichText::new(hint.to_string()).small().weak());
+                }
+            });
+            ui.add_space(5.0);
         }
     }
 
-    /// Function to keep track of which letters have been used and their appearance in the word.
-    fn update_alphabet_state(&mut self) {
-        for (letters, states) in self
+    /// Helper function for a key that shows the state of its letter on every unsolved board, a strip per board
+    fn split_key(&self, ui: &mut egui::Ui, letter: &str) -> egui::Response {
+        let (rect, response) =
+            ui.allocate_exact_size(Vec2 { x: 40.0, y: 40.0 }, egui::Sense::click());
+        let states: Vec<LetterState> = self
             .game_logic
-            .guesses_letters
+            .boards
             .iter()
-            .zip(self.game_logic.guesses.iter_mut())
-        {
-            for (letter, state) in letters.iter().zip(states.iter_mut()) {
-                self.game_logic
-                    .alphabet
-                    .entry(*letter)
-                    .and_modify(|e| *e = *state);
-            }
+            .zip(&self.game_logic.solved)
+            .filter(|(_, solved)| solved.is_none())
+            .map(|(board, _)| *board.alphabet.get(letter).unwrap_or(&LetterState::Unknown))
+            .collect();
+
+        // once every board is solved the key is shown as unused
+        let strips = states.len().max(1);
+        let width = rect.width() / strips as f32;
+        for strip in 0..strips {
+            let state = states.get(strip).copied().unwrap_or(LetterState::Unknown);
+            let strip_rect = egui::Rect::from_min_size(
+                rect.min
+                    + Vec2 {
+                        x: width * strip as f32,
+                        y: 0.0,
+                    },
+                Vec2 {
+                    x: width,
+                    y: rect.height(),
+                },
+            );
+            ui.painter().rect_filled(strip_rect, 0.0, key_color(state));
+        }
+
+        // the letter gets a shadow so it can be read on light and dark strips
+        let font = egui::FontId::proportional(18.0);
+        for (offset, color) in [
+            (Vec2 { x: 1.0, y: 1.0 }, egui::Color32::BLACK),
+            (Vec2::ZERO, egui::Color32::WHITE),
+        ] {
+            ui.painter().text(
+                rect.center() + offset,
+                egui::Align2::CENTER_CENTER,
+                letter,
+                font.clone(),
+                color,
+            );
         }
+
+        response
     }
 
     /// Function to consolidate all the updating on GUI
     fn update_visuals(&mut self, ctx: &egui::Context) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.vertical_centered_justified(|ui| {
-                let button_size = egui::vec2(80.0, 80.0);
-
-                // Render the guesses grid
-                for row in 0..6 {
-                    ui.horizontal(|ui| {
-                        for col in 0..5 {
-                            let cell_color = match self.game_logic.guesses.get(row) {
-                                Some(guess) => match guess[col] {
-                                    LetterState::Correct => egui::Color32::GREEN,
-                                    LetterState::Present => egui::Color32::YELLOW,
-                                    LetterState::Wrong => egui::Color32::from_gray(50),
-                                    LetterState::Unknown => egui::Color32::from_gray(50),
-                                },
-                                None => egui::Color32::from_gray(80),
-                            };
-
-                            let letter = if row == self.game_logic.current_row {
-                                self.game_logic.current_guess[col].to_string()
-                            } else {
-                                match self.game_logic.guesses.get(row) {
-                                    Some(_) => self.game_logic.guesses_letters[row][col].to_string(),
-                                    None => " ".to_string(),
-                                }
-                            };
-
-                            let text = if letter == " " { "_" } else { &letter };
-                            match cell_color{
-                                egui::Color32::GREEN => {
-                                    let button = egui::Button::new(RichText::new(text).color(egui::Color32::BLACK))
-                                    .min_size(button_size)
-                                    .fill(cell_color);
-                                    ui.add(button);
-                                }
-                                egui::Color32::YELLOW => {
-                                    let button = egui::Button::new(RichText::new(text).color(egui::Color32::BLACK))
-                                    .min_size(button_size)
-                                    .fill(cell_color);
-                                    ui.add(button);
-                                }
-                                _ => {
-                                    let button = egui::Button::new(text)
-                                    .min_size(button_size)
-                                    .fill(cell_color);
-                                    ui.add(button);
-                                }
-                            }
+                // longer words and more attempts get smaller tiles so the grid still fits the window,
+                // several boards are shown two side by side
+                let word_length = self.game_logic.word_length;
+                let attempts = self.game_logic.max_attempts;
+                let board_count = self.game_logic.boards.len();
+                let board_columns = board_count.min(2);
+                let tile_size = ((ui.available_width() - BOARD_SPACING * (board_columns - 1) as f32)
+                    / (board_columns * word_length) as f32
+                    - 8.0)
+                    .min(80.0)
+                    .min(480.0 / attempts as f32);
 
-                        }
-                    });
-                    ui.add_space(5.0);
+                // Render the guesses grid, reverse mode shows the colors to match instead
+                if let Some(game) = &self.reverse {
+                    self.show_reverse_board(ui, game, tile_size);
+                } else if board_count == 1 {
+                    self.show_board(ui, 0, tile_size);
+                } else {
+                    egui::ScrollArea::vertical()
+                        .id_source("boards")
+                        .max_height(500.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new("boards")
+                                .spacing([BOARD_SPACING, BOARD_SPACING])
+                                .show(ui, |ui| {
+                                    for board in 0..board_count {
+                                        ui.vertical(|ui| self.show_board(ui, board, tile_size));
+                                        if board % board_columns == board_columns - 1 {
+                                            ui.end_row();
+                                        }
+                                    }
+                                });
+                        });
                 }
 
                 ui.add_space(20.0);
 
-                // Render the alphabet grid
+                // Render the alphabet grid of the language, clicking a letter types it
+                // with several boards every key shows the state of its letter on each unsolved board
                 ui.horizontal_wrapped(|ui| {
-                    for letter in 'A'..='Z' {
-                        let state = self.game_logic.alphabet.get(&letter).unwrap_or(&LetterState::Wrong);
-                        let color = match state {
-                            LetterState::Correct => egui::Color32::GREEN,
-                            LetterState::Present => egui::Color32::YELLOW,
-                            LetterState::Wrong => egui::Color32::from_gray(50),
-                            LetterState::Unknown => egui::Color32::from_gray(80),
+                    for letter in self.game_logic.language.letters() {
+                        let clicked = if board_count == 1 {
+                            let state = self.game_logic.boards[0].alphabet.get(letter).unwrap_or(&LetterState::Wrong);
+                            let button = egui::Button::new(letter.to_string())
+                                .min_size(Vec2 { x: 40.0, y: 40.0 })
+                                .fill(key_color(*state));
+                            ui.add(button).clicked()
+                        } else {
+                            self.split_key(ui, letter).clicked()
                         };
 
-                        let button = egui::Button::new(letter.to_string())
-                            .min_size(Vec2 { x: 40.0, y: 40.0 })
-                            .fill(color);
-
-                        ui.add(button);
+                        if clicked {
+                            self.insert_tile(letter.to_string());
+                        }
                     }
                 });
 
@@ -313,54 +1892,200 @@ impl MyEguiApp {
                 // Render the Submit and Restart buttons
                 ui.vertical_centered_justified(|ui| {
                     if ui.button("Submit Guess").clicked() {
-                        match self.game_logic.submit_guess(self.dictionary.as_ref()){
-                            (GameState::CorrectGuess, _) => {
-                                self.update_alphabet_state();
-                                self.current_row += 1;
-                                self.index = 0;
-                                self.game_state = Some(GameState::CorrectGuess);
+                        self.submit_guess();
+                    }
+
+                    ui.add_space(20.0);
+
+                    if ui.button("Restart").clicked() {
+                        self.request_new_game(NewGame::Random);
+                    }
+
+                    ui.add_space(10.0);
+
+                    if ui.button("Daily Puzzle").clicked() {
+                        self.request_new_game(NewGame::Daily(daily::today()));
+                    }
+
+                    ui.add_space(10.0);
+
+                    if ui.button("Daily Archive").clicked() {
+                        self.show_archive = !self.show_archive;
+                    }
+
+                    ui.add_space(10.0);
+
+                    if ui.button("Absurdle").clicked() {
+                        self.request_new_game(NewGame::Absurdle);
+                    }
+
+                    ui.add_space(10.0);
+
+                    // two players on one machine take turns setting the word
+                    if ui.button("Two Players").clicked() {
+                        self.hot_seat_window.show = !self.hot_seat_window.show;
+                    }
+
+                    ui.add_space(10.0);
+
+                    // find words that give the colors of a grid a friend shared
+                    if ui.button("Reverse Puzzle").clicked() {
+                        self.reverse_window.show = !self.reverse_window.show;
+                    }
+
+                    ui.add_space(10.0);
+
+                    // against the clock: as many words as possible in a few minutes, or one word as fast as possible
+                    ui.horizontal(|ui| {
+                        ui.label("Timed:");
+                        for minutes in [3, 5, 10] {
+                            if ui.button(format!("{} min", minutes)).clicked() {
+                                self.request_new_game(NewGame::Timed(TimerKind::Countdown {
+                                    minutes,
+                                }));
                             }
-                            (GameState::WrongGuess, word) => {
-                                println!("{}", GameState::WrongGuess);
-                                self.game_state = Some(GameState::WrongGuess);
-                                self.last_guessed_word = word;
+                        }
+                        if ui.button("Speed Run").clicked() {
+                            self.request_new_game(NewGame::Timed(TimerKind::Stopwatch));
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // word after word, unused attempts carry over to the next word
+                    ui.horizontal(|ui| {
+                        ui.label("Marathon:");
+                        if ui.button("Start").clicked() {
+                            self.request_new_game(NewGame::Marathon);
+                        }
+                        if ui.button("Best Runs").clicked() {
+                            self.show_marathon_table = !self.show_marathon_table;
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // a code of symbols instead of a word, any combination of them can be guessed
+                    ui.horizontal(|ui| {
+                        ui.label("Mastermind:");
+                        for symbols in SYMBOL_SETS {
+                            if ui.button(symbols.name).clicked() {
+                                self.request_new_game(NewGame::Mastermind(symbols));
                             }
-                            (GameState::Lost, _) => {
-                                self.game_state = Some(GameState::Lost);
-                                self.update_alphabet_state();
-                                self.current_row += 1;
+                        }
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.symbol_text)
+                                .hint_text("A B C D")
+                                .desired_width(80.0),
+                        );
+                        if ui.button("Play").clicked() {
+                            match symbols::custom_symbols(&self.symbol_text) {
+                                Ok(symbols) => {
+                                    self.request_new_game(NewGame::Mastermind(symbols))
+                                }
+                                Err(e) => println!("{}", e),
                             }
-                            (GameState::Won, _) => {
-                                self.game_state = Some(GameState::Won);
-                                self.update_alphabet_state();
-                                self.current_row += 1;
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // several boards at once, each guess is played on every unsolved board
+                    ui.horizontal(|ui| {
+                        ui.label("Boards:");
+                        for (name, boards) in [("Dordle", 2), ("Quordle", 4), ("Octordle", 8)] {
+                            if ui.button(name).clicked() {
+                                self.request_new_game(NewGame::MultiBoard(boards));
                             }
                         }
-                    }
+                        ui.add(egui::DragValue::new(&mut self.board_count).range(2..=MAX_BOARDS));
+                        if ui.button("Play").clicked() {
+                            self.request_new_game(NewGame::MultiBoard(self.board_count));
+                        }
+                    });
 
-                    ui.add_space(20.0);
+                    ui.add_space(10.0);
 
-                    if ui.button("Restart").clicked() {
-                        self.new_game();
-                    }
+                    // puzzles for friends are shared as codes
+                    ui.columns(2, |columns| {
+                        if columns[0].button("Create Puzzle").clicked() {
+                            self.puzzle_windows.show_create = !self.puzzle_windows.show_create;
+                        }
+                        if columns[1].button("Play Code").clicked() {
+                            self.puzzle_windows.show_play = !self.puzzle_windows.show_play;
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // the difficulty is used for the next word
+                    ui.horizontal(|ui| {
+                        ui.label("Difficulty:");
+                        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+                            ui.radio_value(&mut self.difficulty, difficulty, difficulty.to_string());
+                        }
+                        ui.label(format!("(this word: {})", self.word_difficulty));
+                    });
                 });
 
                 ui.add_space(20.0);
                 egui::ScrollArea::vertical().show(ui, |ui|{
+                    self.show_timer(ui);
+                    self.show_marathon_status(ui);
+                    if let (GameMode::Daily { day, archive }, Some(GameState::Won | GameState::Lost)) = (self.mode, self.game_state) {
+                        self.show_daily_status(ui, day, archive);
+                        ui.add_space(10.0);
+                    }
+                    if self.mode == GameMode::Absurdle && self.game_logic.answer().is_none() {
+                        ui.label(format!("Absurdle: {} words are still possible", self.game_logic.boards[0].secret.remaining()));
+                        ui.add_space(10.0);
+                    }
+                    if let (Some(game), None | Some(GameState::CorrectGuess | GameState::WrongGuess)) = (&self.reverse, self.game_state) {
+                        ui.label(format!("Reverse: find a word for every row that gives its colors against {}. The number next to a row is how many words do.", self.language.visual(&game.answer.word)));
+                        if let Some(message) = &self.reverse_message {
+                            ui.label(message);
+                        }
+                        ui.add_space(10.0);
+                    }
+
                     match self.game_state {
                         Some(GameState::CorrectGuess) => {}
                         Some(GameState::WrongGuess) => {
                             let guessed_word: String = self.last_guessed_word.clone().unwrap();
-                            ui.label(format!("{} is not long enough or not in the dicitonary", guessed_word));
+                            ui.label(format!("{} is not long enough or not in the dicitonary", self.language.visual(&guessed_word)));
+
+                            // a complete guess the dictionary doesn't know can be added to the player's own words
+                            let complete = self.game_logic.current_guess.iter().all(|tile| !tile.is_empty());
+                            if complete && ui.button("Add to my words").clicked() {
+                                self.add_to_my_words();
+                            }
+                        }
+                        Some(state @ (GameState::Won | GameState::Lost)) if self.mode == GameMode::Mastermind => {
+                            if state == GameState::Won {
+                                ui.label(format!("You cracked the code in {} guesses: {}", self.game_logic.current_row, self.word));
+                            } else {
+                                ui.label(format!("Sorry you lost :( \n \n  The code was: {}", self.word));
+                            }
+                            self.share_button(ui);
                         }
                         Some(GameState::Lost) => {
-                            ui.label(format!("Sorry you lost :( \n \n  The word was: {} \n Here's the defintion of the word if you are curious: \n {}", self.word, self.definition));
+                            ui.label(format!("Sorry you lost :( \n \n  The word was: {} \n Here's the defintion of the word if you are curious:", self.answers_text()));
+                            self.show_definition(ui);
+                            if board_count == 1 && self.reverse.is_none() {
+                                self.share_button(ui);
+                                self.deny_button(ui);
+                            }
                         }
                         Some(GameState::Won) => {
                             ui.label(format!(
-                                "Congratulations you won! \n\nHere's the definition of the word if you are curious: \n{}",
-                                self.definition
+                                "Congratulations you won! The word was: {} \n\nHere's the definition of the word if you are curious:",
+                                self.answers_text()
                             ));
+                            self.show_definition(ui);
+                            if board_count == 1 && self.reverse.is_none() {
+                                self.share_button(ui);
+                                self.deny_button(ui);
+                            }
                         }
                         _ => {}
                     }
@@ -376,5 +2101,13 @@ impl eframe::App for MyEguiApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.handle_keyboard_input(ctx);
         self.update_visuals(ctx);
+        self.show_archive_window(ctx);
+        self.show_puzzle_windows(ctx);
+        self.update_timer(ctx);
+        self.show_results_window(ctx);
+        self.show_marathon_table_window(ctx);
+        self.show_reverse_window(ctx);
+        self.show_hot_seat_window(ctx);
+        self.show_confirm_window(ctx);
     }
 }
@@ -1,120 +1,512 @@
+use crate::language::Language;
 use crate::states::{GameState, LetterState};
 use crate::WORLD_LENGTH;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Number of guesses a game has unless it is set otherwise
+pub const DEFAULT_ATTEMPTS: usize = WORLD_LENGTH + 1;
+
+/// Most guesses a game can be set to, more rows don't fit the window
+pub const MAX_ATTEMPTS: usize = 10;
+
+/// Most boards a multi-board game can have
+pub const MAX_BOARDS: usize = 16;
+
+/// A possible answer: the word as it is written in the dictionary together with its tiles
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub word: String,
+    pub tiles: Vec<String>,
+}
+
+impl Candidate {
+    pub fn new(word: String, language: &Language) -> Self {
+        let tiles = language.tiles(&word);
+        Self { word, tiles }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Enum for the answer of a game
+/// - Fixed: the answer is picked before the first guess
+/// - Adversarial: no answer is picked, every guess gets the feedback that keeps the most candidates possible (Absurdle).
+///   The game commits to an answer once a single candidate is left.
+pub enum Secret {
+    Fixed(Candidate),
+    Adversarial(Vec<Candidate>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum for the rule a guess has to follow to be played
+/// - Dictionary: the guess is one of the accepted guesses of the language
+/// - AnyCombination: every guess made of the game's symbols is valid (Mastermind)
+pub enum ValidityRule {
+    Dictionary,
+    AnyCombination,
+}
+
+/// Function that colors the tiles of a guess against an answer.
+/// Letters in the right place are Correct, letters that are somewhere else in the answer are Present.
+/// A letter of the answer colors at most one letter of the guess, so a repeated letter is only Present
+/// as often as the answer has it outside of the Correct places.
+/// The tiles can be of any type, letters of a language as well as the symbols of a code.
+pub fn score_guess<T: Eq + Hash>(guess: &[T], answer: &[T]) -> Vec<LetterState> {
+    let mut guess_state = vec![LetterState::Wrong; guess.len()];
+
+    // check if correct letter in the correct position, the other letters of the answer are counted
+    let mut unmatched: HashMap<&T, usize> = HashMap::new();
+    for (i, (letter, answer_letter)) in guess.iter().zip(answer).enumerate() {
+        if letter == answer_letter {
+            guess_state[i] = LetterState::Correct;
+        } else {
+            *unmatched.entry(answer_letter).or_default() += 1;
+        }
+    }
+
+    // check if correct letter, every counted letter of the answer is used up once
+    for (i, letter) in guess.iter().enumerate() {
+        if guess_state[i] == LetterState::Correct {
+            continue;
+        }
+        if let Some(count) = unmatched.get_mut(letter).filter(|count| **count > 0) {
+            *count -= 1;
+            guess_state[i] = LetterState::Present;
+        }
+    }
+
+    guess_state
+}
+
+impl Secret {
+    /// Method that colors a guess.
+    /// An adversarial secret groups its candidates by the colors they would give the guess
+    /// and keeps the largest group, on a tie the group with the fewest Correct and then Present letters.
+    pub fn score(&mut self, guess: &[String]) -> Vec<LetterState> {
+        let candidates = match self {
+            Secret::Fixed(answer) => return score_guess(guess, &answer.tiles),
+            Secret::Adversarial(candidates) => std::mem::take(candidates),
+        };
+
+        let mut groups: HashMap<Vec<LetterState>, Vec<Candidate>> = HashMap::new();
+        for candidate in candidates {
+            groups
+                .entry(score_guess(guess, &candidate.tiles))
+                .or_default()
+                .push(candidate);
+        }
+
+        let count = |pattern: &[LetterState], state: LetterState| {
+            pattern.iter().filter(|&&s| s == state).count()
+        };
+        let (pattern, mut kept) = groups
+            .into_iter()
+            .max_by(|(a, a_words), (b, b_words)| {
+                a_words
+                    .len()
+                    .cmp(&b_words.len())
+                    .then_with(|| {
+                        count(b, LetterState::Correct).cmp(&count(a, LetterState::Correct))
+                    })
+                    .then_with(|| {
+                        count(b, LetterState::Present).cmp(&count(a, LetterState::Present))
+                    })
+                    // the same guess always gets the same colors
+                    .then_with(|| b_words[0].word.cmp(&a_words[0].word))
+            })
+            .expect("an adversarial game has candidates");
+
+        *self = if kept.len() == 1 {
+            Secret::Fixed(kept.remove(0))
+        } else {
+            Secret::Adversarial(kept)
+        };
+        pattern
+    }
+
+    /// Method for the answer, None while an adversarial game has more than one candidate left
+    pub fn answer(&self) -> Option<&Candidate> {
+        match self {
+            Secret::Fixed(answer) => Some(answer),
+            Secret::Adversarial(_) => None,
+        }
+    }
+
+    /// Method for the number of answers that are still possible
+    pub fn remaining(&self) -> usize {
+        match self {
+            Secret::Fixed(_) => 1,
+            Secret::Adversarial(candidates) => candidates.len(),
+        }
+    }
+
+    /// Method that settles on an answer, an adversarial game that is lost reveals one of the words it could still be
+    fn commit(&mut self) {
+        if let Secret::Adversarial(candidates) = self {
+            if !candidates.is_empty() {
+                *self = Secret::Fixed(candidates.remove(0));
+            }
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 /// This struct contains the main logic behind the wordle game.
 /// It holds all the previous guesses and the state of each letter.
+/// Letters are tiles of the game's language, which can be more than one char (Ä written with a combining mark, the Dutch IJ).
+/// The answer is a secret that is either fixed or chosen against the guesses (Absurdle).
+/// The tiles don't have to be letters, a symbol set without a word list (digits, colors) is played like a language.
 pub struct WordleGame {
-    word: String,
-    pub current_guess: [char; WORLD_LENGTH],
+    pub secret: Secret,
+    pub language: &'static Language,
+    pub rule: ValidityRule,
+    pub word_length: usize,
+    pub max_attempts: usize,
     pub current_row: usize,
-    pub guesses: Vec<[LetterState; WORLD_LENGTH]>,
-    pub guesses_letters: Vec<[char; WORLD_LENGTH]>,
-    pub alphabet: HashMap<char, LetterState>,
+    pub guesses: Vec<Vec<LetterState>>,
+    pub guesses_letters: Vec<Vec<String>>,
+    pub alphabet: HashMap<String, LetterState>,
 }
 
 impl WordleGame {
     /// Constructor method for the Wordle game that returns itself
-    /// Requires the word that is supposed to be guessed
-    pub fn new(word: String) -> Self {
+    /// Requires the word that is supposed to be guessed and the language it is played in
+    pub fn new(word: String, language: &'static Language) -> Self {
+        Self::with_attempts(word, language, DEFAULT_ATTEMPTS)
+    }
+
+    /// Constructor method for a game with another number of guesses than the default
+    pub fn with_attempts(word: String, language: &'static Language, max_attempts: usize) -> Self {
+        Self::with_secret(
+            Secret::Fixed(Candidate::new(word, language)),
+            language,
+            max_attempts,
+        )
+    }
+
+    /// Constructor method for a Mastermind game: the answer is a code of symbols and any combination of symbols can be guessed
+    pub fn mastermind(code: String, symbols: &'static Language, max_attempts: usize) -> Self {
+        Self {
+            rule: ValidityRule::AnyCombination,
+            ..Self::with_attempts(code, symbols, max_attempts)
+        }
+    }
+
+    /// Constructor method for an adversarial game, the answer is one of the candidates but none is picked up front.
+    /// The candidates are sorted so a game plays the same way every time.
+    pub fn adversarial(
+        mut candidates: Vec<Candidate>,
+        language: &'static Language,
+        max_attempts: usize,
+    ) -> Self {
+        candidates.sort_by(|a, b| a.word.cmp(&b.word));
+        Self::with_secret(Secret::Adversarial(candidates), language, max_attempts)
+    }
+
+    /// Helper function that sets up an empty board for a secret
+    fn with_secret(secret: Secret, language: &'static Language, max_attempts: usize) -> Self {
+        let word_length = match &secret {
+            Secret::Fixed(answer) => answer.tiles.len(),
+            Secret::Adversarial(candidates) => candidates
+                .first()
+                .map(|candidate| candidate.tiles.len())
+                .unwrap_or(language.word_length),
+        };
+
         Self {
-            word: word.to_uppercase(),
-            current_guess: [' '; WORLD_LENGTH],
+            secret,
+            language,
+            rule: ValidityRule::Dictionary,
+            word_length,
+            max_attempts,
             current_row: 0,
             guesses: Vec::new(),
             guesses_letters: Vec::new(),
-            alphabet: ('A'..='Z').map(|c| (c, LetterState::Unknown)).collect(),
+            alphabet: language
+                .letters()
+                .iter()
+                .map(|letter| (letter.to_string(), LetterState::Unknown))
+                .collect(),
         }
     }
 
-    /// Method that checks if the word is contained in the dictionary.
-    /// This method also checks for the suffixes of words.
+    /// Method that checks if a guess can be played under the game's validity rule.
+    /// With the dictionary rule the guess has to be one of the accepted guesses.
+    /// The inflected forms are generated when the dictionary is cleaned, so this is a plain lookup.
+    /// The answer itself is always accepted, a puzzle shared by a friend may use a word of their own word list.
     ///
-    /// **Example**: Boats is not in the dictionary, however boat is
-    fn is_valid_word(&self, word: String, dictionary: &HashMap<String, String>) -> bool {
-        if dictionary.contains_key(&word) {
-            return true;
-        }
-
-        let possible_bases = vec![
-            word.strip_suffix('s'),
-            word.strip_suffix("es"),
-            word.strip_suffix("ed"),
-            word.strip_suffix("ing"),
-        ];
-
-        for base in possible_bases {
-            if let Some(base_word) = base {
-                if dictionary.contains_key(base_word) {
-                    return true;
-                }
+    /// **Example**: Boats is not in the dictionary, however boat is and its plural is accepted
+    pub fn is_valid_guess(&self, guess: &[String], accepted_guesses: &HashSet<String>) -> bool {
+        match self.rule {
+            ValidityRule::Dictionary => {
+                let Some(word) = self.language.guess_key(guess) else {
+                    return false;
+                };
+                accepted_guesses.contains(&word)
+                    || self
+                        .secret
+                        .answer()
+                        .is_some_and(|answer| word == self.language.tiles_key(&answer.tiles))
             }
+            ValidityRule::AnyCombination => guess.iter().all(|tile| self.language.is_letter(tile)),
         }
+    }
 
-        return false;
+    /// Method for the answer as it is written in the dictionary, None while an adversarial game hasn't settled on one
+    pub fn answer(&self) -> Option<&String> {
+        self.secret.answer().map(|answer| &answer.word)
     }
 
-    /// Method to submit a guess
-    /// This method requires the dictionary to check if the word submitted is actually a real word
+    /// Method that scores a guess that was already checked and puts it on the board
+    pub fn play_guess(&mut self, guess: Vec<String>) -> GameState {
+        let guess_state = self.secret.score(&guess);
+        self.guesses_letters.push(guess);
+
+        // store the guesses and update row
+        let won = guess_state
+            .iter()
+            .all(|&state| state == LetterState::Correct);
+        self.guesses.push(guess_state);
+        self.current_row += 1;
+        self.update_alphabet();
+
+        if won {
+            GameState::Won
+        } else if self.current_row >= self.max_attempts {
+            // that was the last attempt
+            self.secret.commit();
+            GameState::Lost
+        } else {
+            GameState::CorrectGuess
+        }
+    }
+
+    /// Function to keep track of which letters have been used and their appearance in the word.
+    fn update_alphabet(&mut self) {
+        for (letters, states) in self.guesses_letters.iter().zip(self.guesses.iter()) {
+            for (letter, state) in letters.iter().zip(states.iter()) {
+                self.alphabet
+                    .entry(letter.clone())
+                    .and_modify(|e| *e = *state);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// This struct plays several boards at once (Dordle, Quordle, Octordle, ...).
+/// All boards share one input row and every guess is played on each board that isn't solved yet.
+/// A single game is a container with one board.
+/// An empty tile in the current guess is an empty string.
+pub struct MultiBoardGame {
+    pub boards: Vec<WordleGame>,
+    pub language: &'static Language,
+    pub word_length: usize,
+    pub max_attempts: usize,
+    pub current_guess: Vec<String>,
+    pub current_row: usize,
+    /// the row each board was solved in, None while it is unsolved
+    pub solved: Vec<Option<usize>>,
+}
+
+impl MultiBoardGame {
+    /// Constructor method for a game with a single board
+    pub fn single(board: WordleGame) -> Self {
+        Self {
+            language: board.language,
+            word_length: board.word_length,
+            max_attempts: board.max_attempts,
+            current_guess: vec![String::new(); board.word_length],
+            current_row: 0,
+            solved: vec![None],
+            boards: vec![board],
+        }
+    }
+
+    /// Constructor method for a board per word, the boards get 5 guesses more than there are boards
+    pub fn new(words: Vec<String>, language: &'static Language) -> Self {
+        let max_attempts = words.len() + 5;
+        let boards: Vec<WordleGame> = words
+            .into_iter()
+            .map(|word| WordleGame::with_attempts(word, language, max_attempts))
+            .collect();
+        let word_length = boards
+            .first()
+            .map(|board| board.word_length)
+            .unwrap_or(language.word_length);
+
+        Self {
+            language,
+            word_length,
+            max_attempts,
+            current_guess: vec![String::new(); word_length],
+            current_row: 0,
+            solved: vec![None; boards.len()],
+            boards,
+        }
+    }
+
+    /// Method for the answer of the first board, None while an adversarial game hasn't settled on one
+    pub fn answer(&self) -> Option<&String> {
+        self.boards.first().and_then(|board| board.answer())
+    }
+
+    /// Method for the answers of all boards that are known
+    pub fn answers(&self) -> Vec<&String> {
+        self.boards
+            .iter()
+            .filter_map(|board| board.answer())
+            .collect()
+    }
+
+    /// Method to submit the guess of the input row to every unsolved board.
+    /// This method requires the accepted guesses to check if the word submitted is actually a real word,
+    /// the guess is checked once and the answer of any board is accepted as well.
     pub fn submit_guess(
         &mut self,
-        dictionary: &HashMap<String, String>,
+        accepted_guesses: &HashSet<String>,
     ) -> (GameState, Option<String>) {
         // check if we have exceeded the maximum row count
-        if self.current_row >= WORLD_LENGTH + 1 {
+        if self.current_row >= self.max_attempts {
             return (GameState::Lost, None);
         }
 
-        let guess: String = self.current_guess.iter().collect();
+        let guess: String = self.language.display(&self.current_guess);
         // check if there is an incomplete guess
-        if guess.len() != 5 || self.current_guess.contains(&' ') {
+        if self.current_guess.iter().any(|tile| tile.is_empty()) {
             return (GameState::WrongGuess, Some(guess));
         }
 
         // check if the word submitted is a real word by cross-referencing in the dictionary
-        if !self.is_valid_word(guess.clone().to_lowercase(), dictionary) {
+        if !self
+            .boards
+            .iter()
+            .any(|board| board.is_valid_guess(&self.current_guess, accepted_guesses))
+        {
             return (GameState::WrongGuess, Some(guess));
         }
 
-        self.guesses_letters.push(self.current_guess);
-
-        let mut guess_state = [LetterState::Wrong; 5];
-        let word_letters: Vec<char> = self.word.chars().collect();
-
-        // check if correct letter in the correct position
-        for i in 0..WORLD_LENGTH {
-            if self.current_guess[i] == word_letters[i] {
-                guess_state[i] = LetterState::Correct;
+        for (board, solved) in self.boards.iter_mut().zip(self.solved.iter_mut()) {
+            if solved.is_none() && board.play_guess(self.current_guess.clone()) == GameState::Won {
+                *solved = Some(self.current_row);
             }
         }
+        // update row and reset current guess
+        self.current_row += 1;
+        self.current_guess = vec![String::new(); self.word_length];
 
-        // check if correct letter
-        for i in 0..WORLD_LENGTH {
-            if guess_state[i] == LetterState::Correct {
-                continue;
-            }
-            if let Some(_) = word_letters.iter().position(|&c| {
-                c == self.current_guess[i]
-                    && guess_state[word_letters.iter().position(|&t| t == c).unwrap()]
-                        != LetterState::Correct
-            }) {
-                guess_state[i] = LetterState::Present;
-            }
+        if self.solved.iter().all(|solved| solved.is_some()) {
+            (GameState::Won, None)
+        } else if self.current_row >= self.max_attempts {
+            // that was the last attempt
+            (GameState::Lost, None)
+        } else {
+            (GameState::CorrectGuess, None)
         }
+    }
+}
 
-        // store the guesses, update row and reset current guess
-        self.guesses.push(guess_state);
-        self.current_row += 1;
-        self.current_guess = [' '; WORLD_LENGTH];
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::ENGLISH;
+    use LetterState::{Correct, Present, Wrong};
 
-        if guess_state
-            .iter()
-            .all(|&state| state == LetterState::Correct)
-        {
-            return (GameState::Won, None);
-        } else {
-            return (GameState::CorrectGuess, None);
+    fn tiles(word: &str) -> Vec<String> {
+        word.chars().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn repeated_symbols_are_present_once_per_symbol_of_the_code() {
+        assert_eq!(
+            score_guess(&[1, 1, 1, 0], &[0, 0, 0, 1]),
+            vec![Present, Wrong, Wrong, Present]
+        );
+        assert_eq!(
+            score_guess(&tiles("1110"), &tiles("0001")),
+            vec![Present, Wrong, Wrong, Present]
+        );
+    }
+
+    #[test]
+    fn repeated_letters_are_present_once_per_letter_of_the_answer() {
+        assert_eq!(
+            score_guess(&tiles("SPEED"), &tiles("ABIDE")),
+            vec![Wrong, Wrong, Present, Wrong, Present]
+        );
+        assert_eq!(
+            score_guess(&tiles("EERIE"), &tiles("THEME")),
+            vec![Present, Wrong, Wrong, Wrong, Correct]
+        );
+        assert_eq!(
+            score_guess(&tiles("LLAMA"), &tiles("HELLO")),
+            vec![Present, Present, Wrong, Wrong, Wrong]
+        );
+    }
+
+    #[test]
+    fn a_game_is_lost_with_its_last_guess() {
+        let mut game = WordleGame::with_attempts("crane".to_string(), &ENGLISH, 2);
+        assert_eq!(
+            game.play_guess(ENGLISH.tiles("slate")),
+            GameState::CorrectGuess
+        );
+        assert_eq!(game.play_guess(ENGLISH.tiles("pious")), GameState::Lost);
+
+        let mut game = WordleGame::with_attempts("crane".to_string(), &ENGLISH, 2);
+        game.play_guess(ENGLISH.tiles("slate"));
+        assert_eq!(game.play_guess(ENGLISH.tiles("crane")), GameState::Won);
+    }
+
+    /// Helper function for an adversarial secret with the given candidates
+    fn adversarial(words: &[&str]) -> Secret {
+        Secret::Adversarial(
+            words
+                .iter()
+                .map(|word| Candidate {
+                    word: word.to_string(),
+                    tiles: tiles(word),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn adversarial_scoring_keeps_the_largest_group() {
+        let mut secret = adversarial(&["abd", "xyz", "xyw"]);
+        assert_eq!(secret.score(&tiles("abc")), vec![Wrong, Wrong, Wrong]);
+        assert_eq!(secret.remaining(), 2);
+    }
+
+    #[test]
+    fn adversarial_ties_keep_the_fewest_correct_letters() {
+        let mut secret = adversarial(&["axy", "xya"]);
+        assert_eq!(secret.score(&tiles("abc")), vec![Present, Wrong, Wrong]);
+        assert_eq!(
+            secret.answer().map(|answer| answer.word.as_str()),
+            Some("xya")
+        );
+    }
+
+    #[test]
+    fn adversarial_ties_keep_the_fewest_present_letters() {
+        let mut secret = adversarial(&["xya", "xyz"]);
+        assert_eq!(secret.score(&tiles("abc")), vec![Wrong, Wrong, Wrong]);
+        assert_eq!(
+            secret.answer().map(|answer| answer.word.as_str()),
+            Some("xyz")
+        );
+    }
+
+    #[test]
+    fn adversarial_ties_keep_the_first_word() {
+        for _ in 0..10 {
+            let mut secret = adversarial(&["xyb", "xay"]);
+            assert_eq!(secret.score(&tiles("abc")), vec![Present, Wrong, Wrong]);
+            assert_eq!(
+                secret.answer().map(|answer| answer.word.as_str()),
+                Some("xay")
+            );
         }
     }
 }
@@ -0,0 +1,250 @@
+/// First precomposed Hangul syllable (가)
+const SYLLABLE_BASE: u32 = 0xAC00;
+/// Number of precomposed Hangul syllables
+const SYLLABLE_COUNT: u32 = 11172;
+
+/// Initial consonants in the order of the Unicode syllable block
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Vowels in the order of the Unicode syllable block
+const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+/// Final consonants in the order of the Unicode syllable block, the first entry is "no final"
+const FINALS: [Option<char>; 28] = [
+    None,
+    Some('ㄱ'),
+    Some('ㄲ'),
+    Some('ㄳ'),
+    Some('ㄴ'),
+    Some('ㄵ'),
+    Some('ㄶ'),
+    Some('ㄷ'),
+    Some('ㄹ'),
+    Some('ㄺ'),
+    Some('ㄻ'),
+    Some('ㄼ'),
+    Some('ㄽ'),
+    Some('ㄾ'),
+    Some('ㄿ'),
+    Some('ㅀ'),
+    Some('ㅁ'),
+    Some('ㅂ'),
+    Some('ㅄ'),
+    Some('ㅅ'),
+    Some('ㅆ'),
+    Some('ㅇ'),
+    Some('ㅈ'),
+    Some('ㅊ'),
+    Some('ㅋ'),
+    Some('ㅌ'),
+    Some('ㅍ'),
+    Some('ㅎ'),
+];
+
+/// Double consonants, compound vowels and consonant clusters with the basic jamo they are made of
+const COMPOUNDS: [(char, char, char); 23] = [
+    ('ㄲ', 'ㄱ', 'ㄱ'),
+    ('ㄸ', 'ㄷ', 'ㄷ'),
+    ('ㅃ', 'ㅂ', 'ㅂ'),
+    ('ㅆ', 'ㅅ', 'ㅅ'),
+    ('ㅉ', 'ㅈ', 'ㅈ'),
+    ('ㄳ', 'ㄱ', 'ㅅ'),
+    ('ㄵ', 'ㄴ', 'ㅈ'),
+    ('ㄶ', 'ㄴ', 'ㅎ'),
+    ('ㄺ', 'ㄹ', 'ㄱ'),
+    ('ㄻ', 'ㄹ', 'ㅁ'),
+    ('ㄼ', 'ㄹ', 'ㅂ'),
+    ('ㄽ', 'ㄹ', 'ㅅ'),
+    ('ㄾ', 'ㄹ', 'ㅌ'),
+    ('ㄿ', 'ㄹ', 'ㅍ'),
+    ('ㅀ', 'ㄹ', 'ㅎ'),
+    ('ㅄ', 'ㅂ', 'ㅅ'),
+    ('ㅘ', 'ㅗ', 'ㅏ'),
+    ('ㅙ', 'ㅗ', 'ㅐ'),
+    ('ㅚ', 'ㅗ', 'ㅣ'),
+    ('ㅝ', 'ㅜ', 'ㅓ'),
+    ('ㅞ', 'ㅜ', 'ㅔ'),
+    ('ㅟ', 'ㅜ', 'ㅣ'),
+    ('ㅢ', 'ㅡ', 'ㅣ'),
+];
+
+/// Helper function that splits a jamo into the basic jamo it is made of (ㄲ -> ㄱㄱ, ㅘ -> ㅗㅏ)
+fn split_compound(jamo: char, out: &mut Vec<String>) {
+    match COMPOUNDS.iter().find(|(compound, _, _)| *compound == jamo) {
+        Some((_, first, second)) => {
+            out.push(first.to_string());
+            out.push(second.to_string());
+        }
+        None => out.push(jamo.to_string()),
+    }
+}
+
+/// Helper function that joins two basic jamo into a compound if they form one
+fn join_compound(first: &str, second: &str) -> Option<char> {
+    COMPOUNDS
+        .iter()
+        .find(|(_, a, b)| a.to_string() == first && b.to_string() == second)
+        .map(|(compound, _, _)| *compound)
+}
+
+/// Helper function to check if a jamo is a vowel
+fn is_vowel(jamo: &str) -> bool {
+    let mut chars = jamo.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if MEDIALS.contains(&c))
+}
+
+/// Helper function to check if a jamo is a consonant
+fn is_consonant(jamo: &str) -> bool {
+    let mut chars = jamo.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if ('ㄱ'..='ㅎ').contains(&c))
+}
+
+/// Function that decomposes Hangul text into basic jamo, one per tile.
+/// Syllables are split into their initial, vowel and final, and double consonants, compound vowels
+/// and consonant clusters are split further (까 -> ㄱㄱㅏ, 와 -> ㅇㅗㅏ).
+/// Anything that is not Hangul is kept as it is.
+pub fn decompose(text: &str) -> Vec<String> {
+    let mut jamo = Vec::new();
+
+    for c in text.chars() {
+        let code = c as u32;
+        if (SYLLABLE_BASE..SYLLABLE_BASE + SYLLABLE_COUNT).contains(&code) {
+            let index = code - SYLLABLE_BASE;
+            split_compound(INITIALS[(index / (21 * 28)) as usize], &mut jamo);
+            split_compound(MEDIALS[((index % (21 * 28)) / 28) as usize], &mut jamo);
+            if let Some(last) = FINALS[(index % 28) as usize] {
+                split_compound(last, &mut jamo);
+            }
+        } else {
+            split_compound(c, &mut jamo);
+        }
+    }
+
+    jamo
+}
+
+/// Helper function to build a syllable from its parts
+fn syllable(initial: char, medial: char, last: Option<char>) -> Option<char> {
+    let l = INITIALS.iter().position(|&c| c == initial)? as u32;
+    let v = MEDIALS.iter().position(|&c| c == medial)? as u32;
+    let t = FINALS.iter().position(|&c| c == last)? as u32;
+    char::from_u32(SYLLABLE_BASE + (l * 21 + v) * 28 + t)
+}
+
+/// Function that recomposes basic jamo into syllables for display (ㄱㅏㅁㅈㅏ -> 감자).
+/// Like a Korean keyboard, a consonant followed by a vowel always starts a new syllable.
+/// Incomplete input (a consonant without a vowel) and empty tiles are shown as they are.
+pub fn compose(jamo: &[String]) -> String {
+    let mut text = String::new();
+    let mut i = 0;
+    let at = |i: usize| jamo.get(i).map(|s| s.as_str()).unwrap_or("");
+
+    while i < jamo.len() {
+        // initial consonant, a doubled consonant only counts as one if a vowel follows
+        let (initial, used) = match join_compound(at(i), at(i + 1)) {
+            Some(double) if is_consonant(at(i)) && is_vowel(at(i + 2)) => (double, 2),
+            _ if is_consonant(at(i)) && is_vowel(at(i + 1)) => (at(i).chars().next().unwrap(), 1),
+            _ => {
+                text.push_str(at(i));
+                i += 1;
+                continue;
+            }
+        };
+        i += used;
+
+        // vowel, possibly compound
+        let medial = match join_compound(at(i), at(i + 1)) {
+            Some(compound) if is_vowel(at(i)) && is_vowel(at(i + 1)) => {
+                i += 2;
+                compound
+            }
+            _ => {
+                i += 1;
+                at(i - 1).chars().next().unwrap()
+            }
+        };
+
+        // final consonant, unless the consonant starts the next syllable
+        let mut last = None;
+        if is_consonant(at(i)) && !is_vowel(at(i + 1)) {
+            match join_compound(at(i), at(i + 1)) {
+                Some(cluster)
+                    if is_consonant(at(i + 1))
+                        && !is_vowel(at(i + 2))
+                        && FINALS.contains(&Some(cluster)) =>
+                {
+                    last = Some(cluster);
+                    i += 2;
+                }
+                _ if FINALS.contains(&at(i).chars().next()) => {
+                    last = at(i).chars().next();
+                    i += 1;
+                }
+                _ => {}
+            }
+        }
+
+        match syllable(initial, medial, last) {
+            Some(c) => text.push(c),
+            None => {
+                text.push(initial);
+                text.push(medial);
+                if let Some(last) = last {
+                    text.push(last);
+                }
+            }
+        }
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(tiles: &[&str]) -> Vec<String> {
+        tiles.iter().map(|tile| tile.to_string()).collect()
+    }
+
+    #[test]
+    fn compounds_are_split_into_basic_jamo() {
+        assert_eq!(decompose("까"), tiles(&["ㄱ", "ㄱ", "ㅏ"]));
+        assert_eq!(decompose("와"), tiles(&["ㅇ", "ㅗ", "ㅏ"]));
+        assert_eq!(decompose("닭"), tiles(&["ㄷ", "ㅏ", "ㄹ", "ㄱ"]));
+        assert_eq!(decompose("ㅢ"), tiles(&["ㅡ", "ㅣ"]));
+    }
+
+    #[test]
+    fn basic_jamo_are_composed_back() {
+        assert_eq!(compose(&tiles(&["ㄱ", "ㄱ", "ㅏ"])), "까");
+        assert_eq!(compose(&tiles(&["ㅇ", "ㅗ", "ㅏ"])), "와");
+        assert_eq!(compose(&tiles(&["ㄷ", "ㅏ", "ㄹ", "ㄱ"])), "닭");
+        assert_eq!(compose(&tiles(&["ㄱ", "ㅏ", "ㄱ"])), "각");
+        // incomplete input is shown as it is typed
+        assert_eq!(compose(&tiles(&["ㄱ", ""])), "ㄱ");
+    }
+
+    #[test]
+    fn words_survive_a_round_trip() {
+        for word in [
+            "감자",
+            "까치",
+            "사과",
+            "닭고기",
+            "없다",
+            "의사",
+            "꽃잎",
+            "빨리",
+            "왜냐면",
+        ] {
+            assert_eq!(compose(&decompose(word)), word);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+/// A player of a hot-seat match with their score
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub name: String,
+    /// rounds won, as the guesser by solving the word or as the setter when it wasn't solved
+    pub wins: usize,
+    /// guesses used in the rounds the player guessed
+    pub guesses: usize,
+}
+
+impl Player {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            wins: 0,
+            guesses: 0,
+        }
+    }
+}
+
+/// A finished round of a hot-seat match
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub setter: usize,
+    pub won: bool,
+    pub guesses: usize,
+}
+
+#[derive(Debug, Clone)]
+/// This struct keeps the score of two players taking turns on one machine.
+/// Every round one player secretly sets the word and the other one guesses it, the roles swap after each round.
+/// The guesser wins the round by solving the word, otherwise the setter wins it.
+/// A best-of-N match is over once a player has won more than half of the rounds, without N the match goes on.
+pub struct HotSeatMatch {
+    pub players: [Player; 2],
+    /// the player who sets the word of the current round
+    pub setter: usize,
+    pub rounds: Vec<Round>,
+    pub best_of: Option<usize>,
+}
+
+impl HotSeatMatch {
+    /// Constructor method for a match, the first player sets the first word
+    pub fn new(names: [String; 2], best_of: Option<usize>) -> Self {
+        let [first, second] = names;
+        Self {
+            players: [Player::new(first), Player::new(second)],
+            setter: 0,
+            rounds: Vec::new(),
+            best_of,
+        }
+    }
+
+    /// Method for the player who guesses the word of the current round
+    pub fn guesser(&self) -> usize {
+        1 - self.setter
+    }
+
+    /// Method that scores a finished round and swaps the roles for the next one
+    pub fn record(&mut self, won: bool, guesses: usize) {
+        let guesser = self.guesser();
+        self.players[guesser].guesses += guesses;
+        let winner = if won { guesser } else { self.setter };
+        self.players[winner].wins += 1;
+
+        self.rounds.push(Round {
+            setter: self.setter,
+            won,
+            guesses,
+        });
+        self.setter = guesser;
+    }
+
+    /// Method for the winner of a best-of-N match, None while it is still being played
+    pub fn winner(&self) -> Option<usize> {
+        let best_of = self.best_of?;
+        self.players
+            .iter()
+            .position(|player| player.wins > best_of / 2)
+    }
+}
@@ -0,0 +1,633 @@
+use crate::hangul;
+use crate::rtl;
+use crate::{DICT_SHA256, DICT_UTL, WORLD_LENGTH};
+use std::path::PathBuf;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Letters shared by all languages written in the latin alphabet
+const LATIN: [&str; 26] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z",
+];
+
+/// Helper function for ligatures that have no accent to drop but are written out when folding (Œ -> OE)
+fn fold_ligature(grapheme: &str) -> Option<&'static str> {
+    match grapheme {
+        "Œ" => Some("OE"),
+        "Æ" => Some("AE"),
+        _ => None,
+    }
+}
+
+/// Enum for how accented letters that are not part of the alphabet are handled
+/// - Distinct: accented letters are tiles of their own (É is not E)
+/// - Fold: accents are dropped for guessing and scoring (É is played as E)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiacriticMode {
+    Distinct,
+    Fold,
+}
+
+/// Enum for how words are split into tiles
+/// - Graphemes: every letter (grapheme cluster) is a tile
+/// - HangulJamo: Hangul syllables are decomposed into their consonants and vowels, each jamo is a tile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TileScheme {
+    Graphemes,
+    HangulJamo,
+}
+
+/// Enum for letters that change their shape with their position in the word
+/// - Plain: letters always look the same
+/// - HebrewFinals: כ מ נ פ צ are written as ך ם ן ף ץ at the end of a word
+/// - ArabicJoining: letters are joined to their neighbours and take an isolated, final, initial or medial form
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LetterForms {
+    Plain,
+    HebrewFinals,
+    ArabicJoining,
+}
+
+/// Struct for a language pack.
+/// A language pack defines which tiles (letters) the game is played with, where its word list comes from
+/// and how words are normalized before they are split into tiles.
+#[derive(Debug, Clone)]
+pub struct Language {
+    /// short code used to select the language, e.g. "en"
+    pub code: &'static str,
+    pub name: &'static str,
+    /// the tiles of the keyboard in the order they are shown
+    pub alphabet: &'static [&'static str],
+    /// accented letters that are extra tiles when diacritics are distinct and are folded otherwise
+    pub accented_letters: &'static [&'static str],
+    pub diacritics: DiacriticMode,
+    /// tiles that are written with more than one letter (the Dutch IJ) and are used as a single tile
+    pub digraphs: &'static [&'static str],
+    pub tile_scheme: TileScheme,
+    /// whether the language is written right to left, the grid is then filled from the right
+    pub rtl: bool,
+    /// positional forms of the letters, these are only shown and are scored as the base letter
+    pub letter_forms: LetterForms,
+    /// number of tiles of a word
+    pub word_length: usize,
+    /// typed characters that stand for other tiles, e.g. the keys of a Korean keyboard on a latin layout
+    pub keymap: &'static [(char, &'static str)],
+    /// font files that can display the alphabet, the first one that exists is loaded
+    pub fonts: &'static [&'static str],
+    /// name of the word list inside the language's directory
+    pub source: &'static str,
+    /// where the word list can be downloaded from, if it can be
+    pub source_url: Option<&'static str>,
+    /// optional SHA-256 the downloaded word list has to match
+    pub source_sha256: Option<&'static str>,
+    /// whether the English inflection rules are used to generate plurals and verb forms
+    pub inflections: bool,
+}
+
+pub const ENGLISH: Language = Language {
+    code: "en",
+    name: "English",
+    alphabet: &LATIN,
+    accented_letters: &[],
+    diacritics: DiacriticMode::Distinct,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "raw_dictionary.json",
+    source_url: Some(DICT_UTL),
+    source_sha256: DICT_SHA256,
+    inflections: true,
+};
+
+pub const GERMAN: Language = Language {
+    code: "de",
+    name: "Deutsch",
+    alphabet: &[
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R",
+        "S", "T", "U", "V", "W", "X", "Y", "Z", "Ä", "Ö", "Ü", "ẞ",
+    ],
+    accented_letters: &[],
+    diacritics: DiacriticMode::Distinct,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const SPANISH: Language = Language {
+    code: "es",
+    name: "Español",
+    alphabet: &[
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "Ñ", "O", "P", "Q",
+        "R", "S", "T", "U", "V", "W", "X", "Y", "Z",
+    ],
+    accented_letters: &["Á", "É", "Í", "Ó", "Ú", "Ü"],
+    diacritics: DiacriticMode::Fold,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const DUTCH: Language = Language {
+    code: "nl",
+    name: "Nederlands",
+    alphabet: &[
+        "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R",
+        "S", "T", "U", "V", "W", "X", "Y", "Z", "IJ",
+    ],
+    accented_letters: &["É", "Ë", "Ï", "Ö"],
+    diacritics: DiacriticMode::Fold,
+    digraphs: &["IJ"],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const FRENCH: Language = Language {
+    code: "fr",
+    name: "Français",
+    alphabet: &LATIN,
+    accented_letters: &[
+        "À", "Â", "Æ", "Ç", "É", "È", "Ê", "Ë", "Î", "Ï", "Ô", "Œ", "Ù", "Û", "Ü", "Ÿ",
+    ],
+    diacritics: DiacriticMode::Fold,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const PORTUGUESE: Language = Language {
+    code: "pt",
+    name: "Português",
+    alphabet: &LATIN,
+    accented_letters: &[
+        "Á", "À", "Â", "Ã", "Ç", "É", "Ê", "Í", "Ó", "Ô", "Õ", "Ú", "Ü",
+    ],
+    diacritics: DiacriticMode::Fold,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: WORLD_LENGTH,
+    keymap: &[],
+    fonts: &[],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const KOREAN: Language = Language {
+    code: "ko",
+    name: "한국어",
+    alphabet: &[
+        "ㄱ", "ㄴ", "ㄷ", "ㄹ", "ㅁ", "ㅂ", "ㅅ", "ㅇ", "ㅈ", "ㅊ", "ㅋ", "ㅌ", "ㅍ", "ㅎ", "ㅏ",
+        "ㅐ", "ㅑ", "ㅒ", "ㅓ", "ㅔ", "ㅕ", "ㅖ", "ㅗ", "ㅛ", "ㅜ", "ㅠ", "ㅡ", "ㅣ",
+    ],
+    accented_letters: &[],
+    diacritics: DiacriticMode::Distinct,
+    digraphs: &[],
+    tile_scheme: TileScheme::HangulJamo,
+    rtl: false,
+    letter_forms: LetterForms::Plain,
+    word_length: 6,
+    // the standard two set Korean keyboard, shifted keys give the double consonants
+    keymap: &[
+        ('q', "ㅂ"),
+        ('w', "ㅈ"),
+        ('e', "ㄷ"),
+        ('r', "ㄱ"),
+        ('t', "ㅅ"),
+        ('y', "ㅛ"),
+        ('u', "ㅕ"),
+        ('i', "ㅑ"),
+        ('o', "ㅐ"),
+        ('p', "ㅔ"),
+        ('a', "ㅁ"),
+        ('s', "ㄴ"),
+        ('d', "ㅇ"),
+        ('f', "ㄹ"),
+        ('g', "ㅎ"),
+        ('h', "ㅗ"),
+        ('j', "ㅓ"),
+        ('k', "ㅏ"),
+        ('l', "ㅣ"),
+        ('z', "ㅋ"),
+        ('x', "ㅌ"),
+        ('c', "ㅊ"),
+        ('v', "ㅍ"),
+        ('b', "ㅠ"),
+        ('n', "ㅜ"),
+        ('m', "ㅡ"),
+        ('Q', "ㅃ"),
+        ('W', "ㅉ"),
+        ('E', "ㄸ"),
+        ('R', "ㄲ"),
+        ('T', "ㅆ"),
+        ('O', "ㅒ"),
+        ('P', "ㅖ"),
+    ],
+    fonts: &[
+        "/usr/share/fonts/truetype/nanum/NanumGothic.ttf",
+        "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+        "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+        "/System/Library/Fonts/AppleSDGothicNeo.ttc",
+        "C:\\Windows\\Fonts\\malgun.ttf",
+    ],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const HEBREW: Language = Language {
+    code: "he",
+    name: "עברית",
+    alphabet: &[
+        "א", "ב", "ג", "ד", "ה", "ו", "ז", "ח", "ט", "י", "כ", "ל", "מ", "נ", "ס", "ע", "פ", "צ",
+        "ק", "ר", "ש", "ת",
+    ],
+    accented_letters: &[],
+    // niqqud (vowel points) are dropped
+    diacritics: DiacriticMode::Fold,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: true,
+    letter_forms: LetterForms::HebrewFinals,
+    word_length: WORLD_LENGTH,
+    // the standard Hebrew keyboard, the final letters are typed as their base letter
+    keymap: &[
+        ('e', "ק"),
+        ('r', "ר"),
+        ('t', "א"),
+        ('y', "ט"),
+        ('u', "ו"),
+        ('i', "ן"),
+        ('o', "ם"),
+        ('p', "פ"),
+        ('a', "ש"),
+        ('s', "ד"),
+        ('d', "ג"),
+        ('f', "כ"),
+        ('g', "ע"),
+        ('h', "י"),
+        ('j', "ח"),
+        ('k', "ל"),
+        ('l', "ך"),
+        ('z', "ז"),
+        ('x', "ס"),
+        ('c', "ב"),
+        ('v', "ה"),
+        ('b', "נ"),
+        ('n', "מ"),
+        ('m', "צ"),
+        (',', "ת"),
+        ('.', "ץ"),
+    ],
+    fonts: &[
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+    ],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+pub const ARABIC: Language = Language {
+    code: "ar",
+    name: "العربية",
+    alphabet: &[
+        "ا", "ب", "ت", "ث", "ج", "ح", "خ", "د", "ذ", "ر", "ز", "س", "ش", "ص", "ض", "ط", "ظ", "ع",
+        "غ", "ف", "ق", "ك", "ل", "م", "ن", "ه", "و", "ي",
+    ],
+    accented_letters: &[],
+    // harakat and hamza marks are dropped (أ -> ا)
+    diacritics: DiacriticMode::Fold,
+    digraphs: &[],
+    tile_scheme: TileScheme::Graphemes,
+    rtl: true,
+    letter_forms: LetterForms::ArabicJoining,
+    word_length: WORLD_LENGTH,
+    // the standard Arabic keyboard
+    keymap: &[
+        ('q', "ض"),
+        ('w', "ص"),
+        ('e', "ث"),
+        ('r', "ق"),
+        ('t', "ف"),
+        ('y', "غ"),
+        ('u', "ع"),
+        ('i', "ه"),
+        ('o', "خ"),
+        ('p', "ح"),
+        ('[', "ج"),
+        (']', "د"),
+        ('a', "ش"),
+        ('s', "س"),
+        ('d', "ي"),
+        ('f', "ب"),
+        ('g', "ل"),
+        ('h', "ا"),
+        ('j', "ت"),
+        ('k', "ن"),
+        ('l', "م"),
+        (';', "ك"),
+        ('\'', "ط"),
+        ('v', "ر"),
+        ('n', "ى"),
+        ('m', "ة"),
+        (',', "و"),
+        ('.', "ز"),
+        ('/', "ظ"),
+        ('`', "ذ"),
+    ],
+    fonts: &[
+        "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+        "/usr/share/fonts/TTF/DejaVuSans.ttf",
+        "/System/Library/Fonts/Supplemental/Arial Unicode.ttf",
+        "C:\\Windows\\Fonts\\arial.ttf",
+    ],
+    source: "words.txt",
+    source_url: None,
+    source_sha256: None,
+    inflections: false,
+};
+
+/// All language packs that ship with the game
+pub const LANGUAGES: &[&Language] = &[
+    &ENGLISH,
+    &GERMAN,
+    &SPANISH,
+    &DUTCH,
+    &FRENCH,
+    &PORTUGUESE,
+    &KOREAN,
+    &HEBREW,
+    &ARABIC,
+];
+
+impl Language {
+    /// Helper function to find a language pack by its code
+    pub fn from_code(code: &str) -> Option<&'static Language> {
+        LANGUAGES
+            .iter()
+            .copied()
+            .find(|language| language.code.eq_ignore_ascii_case(code))
+    }
+
+    /// Directory that holds the word list and the cleaned dictionary of the language.
+    /// English keeps its files directly in src/utils, other languages get their own sub directory.
+    pub fn dir(&self) -> PathBuf {
+        if self.code == ENGLISH.code {
+            PathBuf::from("src/utils")
+        } else {
+            PathBuf::from("src/utils").join(self.code)
+        }
+    }
+
+    /// Method that returns a copy of the language pack with a different diacritic mode.
+    /// The copy lives for the rest of the program, just like the built in packs.
+    pub fn with_diacritics(&self, diacritics: DiacriticMode) -> &'static Language {
+        Box::leak(Box::new(Language {
+            diacritics,
+            ..self.clone()
+        }))
+    }
+
+    /// Method for all tiles of the keyboard: the alphabet and, if they are distinct, the accented letters
+    pub fn letters(&self) -> Vec<&'static str> {
+        let mut letters = self.alphabet.to_vec();
+        if self.diacritics == DiacriticMode::Distinct {
+            letters.extend_from_slice(self.accented_letters);
+        }
+        letters
+    }
+
+    /// Directory that holds the cleaned dictionary and the accepted guesses.
+    /// These depend on the diacritic mode, so playing with distinct accents in a language that folds them by default
+    /// keeps its own cleaned files in a sub directory.
+    pub fn dictionary_dir(&self) -> PathBuf {
+        if self.diacritics == DiacriticMode::Distinct && !self.accented_letters.is_empty() {
+            self.dir().join("distinct")
+        } else {
+            self.dir()
+        }
+    }
+
+    /// Method that brings a word into the form used for the tiles: composed unicode and upper case.
+    /// In a language with the capital ẞ, ß is upper cased to it instead of to SS, so it stays a single tile.
+    /// Letters written in a positional form (Hebrew final letters, Arabic joined letters) become their base letter.
+    /// When diacritics are folded, accented letters that are not in the alphabet lose their accent (É -> E),
+    /// while letters that belong to the alphabet (the Spanish Ñ) are kept.
+    pub fn normalize(&self, word: &str) -> String {
+        let mut composed = word.trim().nfc().collect::<String>();
+        if self.alphabet.contains(&"ẞ") {
+            composed = composed.replace('ß', "ẞ");
+        }
+        let mut composed = composed.to_uppercase();
+        if self.letter_forms != LetterForms::Plain {
+            composed = composed.chars().map(rtl::base_letter).collect();
+        }
+        if self.diacritics == DiacriticMode::Distinct {
+            return composed;
+        }
+
+        composed
+            .graphemes(true)
+            .map(|grapheme| {
+                if self.is_letter(grapheme) {
+                    grapheme.to_string()
+                } else if let Some(letters) = fold_ligature(grapheme) {
+                    letters.to_string()
+                } else {
+                    grapheme
+                        .nfd()
+                        .filter(|c| !is_combining_mark(*c))
+                        .nfc()
+                        .collect()
+                }
+            })
+            .collect()
+    }
+
+    /// Method for the key a word is looked up with in the accepted guesses, the same for any spelling of the word.
+    /// It is built from the tiles, so a guess put together tile by tile has the same key as the word.
+    pub fn key(&self, word: &str) -> String {
+        self.tiles_key(&self.tiles(word))
+    }
+
+    /// Method for the lookup key of a word that is already split into tiles
+    pub fn tiles_key(&self, tiles: &[String]) -> String {
+        tiles.concat().to_lowercase()
+    }
+
+    /// Method for the lookup key of tiles a player put together.
+    /// The key only compares letters, so it is None if the letters of the tiles make other tiles of the language:
+    /// I and J entered as two tiles are not the IJ tile of a Dutch word.
+    pub fn guess_key(&self, tiles: &[String]) -> Option<String> {
+        (self.tiles(&tiles.concat()) == tiles).then(|| self.tiles_key(tiles))
+    }
+
+    /// Method that turns tiles back into the word as it is written, e.g. Korean jamo into syllables
+    /// or a Hebrew word with its final letter
+    pub fn display(&self, tiles: &[String]) -> String {
+        match (self.tile_scheme, self.letter_forms) {
+            (TileScheme::HangulJamo, _) => hangul::compose(tiles),
+            (_, LetterForms::HebrewFinals) => (0..tiles.len())
+                .map(|index| rtl::hebrew_form(tiles, index))
+                .collect(),
+            _ => tiles.concat(),
+        }
+    }
+
+    /// Method for the letter shown on the tile at a position.
+    /// Letters that change shape with their position get the form for that position, they are still scored as the base letter.
+    pub fn tile_form(&self, tiles: &[String], index: usize) -> String {
+        match self.letter_forms {
+            LetterForms::Plain => tiles[index].clone(),
+            LetterForms::HebrewFinals => rtl::hebrew_form(tiles, index),
+            LetterForms::ArabicJoining => rtl::arabic_form(tiles, index),
+        }
+    }
+
+    /// Method for showing a word in a label.
+    /// egui lays out text left to right and doesn't join letters, so right to left words are shaped and reversed here.
+    pub fn visual(&self, word: &str) -> String {
+        if !self.rtl {
+            return word.to_string();
+        }
+
+        let tiles = self.tiles(word);
+        (0..tiles.len())
+            .rev()
+            .map(|index| self.tile_form(&tiles, index))
+            .collect()
+    }
+
+    /// Method that turns a typed character into tiles.
+    /// Characters of the keymap are replaced first and Korean syllables typed with an IME are decomposed into jamo.
+    pub fn typed_tiles(&self, typed: char) -> Vec<String> {
+        match self.keymap.iter().find(|(key, _)| *key == typed) {
+            Some((_, tile)) => self.tiles(tile),
+            None => self.tiles(&typed.to_string()),
+        }
+    }
+
+    /// Method that splits a word into its tiles.
+    /// Letters are counted as grapheme clusters, so a letter with a combining accent is a single tile,
+    /// and digraphs of the language (the Dutch IJ) are merged into one tile.
+    /// Korean words are split into jamo instead.
+    pub fn tiles(&self, word: &str) -> Vec<String> {
+        let normalized = self.normalize(word);
+        if self.tile_scheme == TileScheme::HangulJamo {
+            return hangul::decompose(&normalized);
+        }
+
+        let graphemes: Vec<&str> = normalized.graphemes(true).collect();
+
+        let mut tiles = Vec::with_capacity(graphemes.len());
+        let mut i = 0;
+        while i < graphemes.len() {
+            let digraph = self.digraphs.iter().find(|digraph| {
+                let parts = digraph.graphemes(true).count();
+                i + parts <= graphemes.len() && graphemes[i..i + parts].concat() == **digraph
+            });
+
+            match digraph {
+                Some(digraph) => {
+                    tiles.push(digraph.to_string());
+                    i += digraph.graphemes(true).count();
+                }
+                None => {
+                    tiles.push(graphemes[i].to_string());
+                    i += 1;
+                }
+            }
+        }
+
+        tiles
+    }
+
+    /// Method that checks if a tile is part of the language's alphabet (including distinct accented letters)
+    pub fn is_letter(&self, tile: &str) -> bool {
+        self.alphabet.contains(&tile)
+            || (self.diacritics == DiacriticMode::Distinct && self.accented_letters.contains(&tile))
+    }
+
+    /// Method that checks if a word can be played: it has at most the given number of tiles and only uses letters of the alphabet
+    pub fn is_playable(&self, word: &str, word_length: usize) -> bool {
+        let tiles = self.tiles(word);
+        !tiles.is_empty() && tiles.len() <= word_length && tiles.iter().all(|t| self.is_letter(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tiles(tiles: &[&str]) -> Vec<String> {
+        tiles.iter().map(|tile| tile.to_string()).collect()
+    }
+
+    #[test]
+    fn german_sharp_s_is_a_single_tile() {
+        assert_eq!(
+            GERMAN.tiles("straße"),
+            tiles(&["S", "T", "R", "A", "ẞ", "E"])
+        );
+        assert_eq!(GERMAN.tiles("maß").len(), 3);
+        assert!(GERMAN.is_playable("fuß", 3));
+        assert_eq!(GERMAN.typed_tiles('ß'), tiles(&["ẞ"]));
+        assert_eq!(
+            GERMAN.key("Fuß"),
+            GERMAN.tiles_key(&tiles(&["F", "U", "ẞ"]))
+        );
+    }
+
+    #[test]
+    fn dutch_ij_only_matches_the_ij_tile() {
+        let word = DUTCH.tiles("ijzer");
+        assert_eq!(word, tiles(&["IJ", "Z", "E", "R"]));
+        assert_eq!(DUTCH.guess_key(&word), Some(DUTCH.key("ijzer")));
+        assert_eq!(DUTCH.guess_key(&tiles(&["I", "J", "Z", "E", "R"])), None);
+    }
+}
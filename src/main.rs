@@ -1,56 +1,219 @@
-use std::path::Path;
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
 
 mod utils;
-use utils::cleaner::clean;
-use utils::download::download_raw_dictionary;
-use utils::selector::json_to_hashmap;
+use utils::blocklist::{BlockCategory, Blocklist};
+use utils::classifier::{load_tags, ExclusionPolicy, TAGS_FILE};
+use utils::cleaner::{clean, is_cleaned_from};
+use utils::daily::DailySchedule;
+use utils::download::{download_raw_dictionary, DownloadOptions};
+use utils::errors::DictionaryError;
+use utils::frequency::load_word_frequencies;
+use utils::personal::PersonalWords;
+use utils::selector::{accepted_guesses, load_dictionary, AnswerPool};
+use utils::stats::{source_words, DictionaryStats};
+use utils::store::{DEFINITIONS_FILE, INDEX_FILE};
 
 mod app;
 use app::MyEguiApp;
 
 mod game_logic;
+mod hangul;
+mod hotseat;
+mod language;
+mod reverse;
+use language::{DiacriticMode, Language, ENGLISH, LANGUAGES};
+mod rtl;
 mod states;
+mod symbols;
 
 pub const WORLD_LENGTH: usize = 5;
 pub const DICT_UTL: &str = "https://raw.githubusercontent.com/matthewreagan/WebstersEnglishDictionary/master/dictionary.json";
+/// Optional SHA-256 of the file at DICT_UTL, set it to pin the exact dictionary that gets downloaded
+pub const DICT_SHA256: Option<&str> = None;
+
+/// Helper function that returns the value following a command line flag, e.g. `--lang de`
+fn arg_value<'a>(args: &'a [String], flag: &str) -> Option<&'a String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|i| args.get(i + 1))
+}
 
 fn main() {
-    let clean_dictionary_path = Path::new("src/utils/clean_dictionary.json");
-    let raw_dictionary_path = Path::new("src/utils/raw_dictionary.json");
+    let args: Vec<String> = env::args().collect();
+
+    // the language pack is picked with --lang <code>, English is the default
+    let language = match arg_value(&args, "--lang") {
+        Some(code) => match Language::from_code(code) {
+            Some(language) => language,
+            None => {
+                let codes: Vec<&str> = LANGUAGES.iter().map(|l| l.code).collect();
+                println!("Unknown language {}, available: {}", code, codes.join(", "));
+                return;
+            }
+        },
+        None => &ENGLISH,
+    };
+
+    // accented letters can be played as their own tiles or folded with --accents distinct|fold
+    let language = match arg_value(&args, "--accents").map(|mode| mode.as_str()) {
+        Some("distinct") => language.with_diacritics(DiacriticMode::Distinct),
+        Some("fold") => language.with_diacritics(DiacriticMode::Fold),
+        Some(mode) => {
+            println!("Unknown accent mode {}, use distinct or fold", mode);
+            return;
+        }
+        None => language,
+    };
+
+    let language_dir = language.dir();
+    let dictionary_dir = language.dictionary_dir();
+    let definitions_path = dictionary_dir.join(DEFINITIONS_FILE);
+    let index_path = dictionary_dir.join(INDEX_FILE);
+    let raw_dictionary_path = language_dir.join(language.source);
+    let accepted_guesses_path = dictionary_dir.join("accepted_guesses.json");
+
+    // a custom word list can be passed with --words <path>, it replaces the word list of the language
+    let custom_source = arg_value(&args, "--words").map(PathBuf::from);
+
+    // `stats` prints statistics about the word list instead of starting the game,
+    // with --words it reads the given list without cleaning it so a new list can be judged before switching to it
+    if args.get(1).map(|arg| arg.as_str()) == Some("stats") {
+        let words = match &custom_source {
+            Some(source) => source_words(source, language),
+            None => load_dictionary(language)
+                .map(|dictionary| dictionary.words().cloned().collect())
+                .map_err(|_| DictionaryError::PathError),
+        };
+        match words {
+            Ok(words) => print!("{}", DictionaryStats::new(&words, language)),
+            Err(e) => println!("{}, start the game once to clean the word list", e),
+        }
+        return;
+    }
+
+    // categories of the blocklist that are never the answer, e.g. --block offensive,adult (all of them by default)
+    let blocked_categories = match arg_value(&args, "--block") {
+        Some(list) => match BlockCategory::parse_list(list) {
+            Some(categories) => categories,
+            None => {
+                println!(
+                    "Unknown category in --block, use offensive, sensitive, adult, all or none"
+                );
+                return;
+            }
+        },
+        None => BlockCategory::ALL.to_vec(),
+    };
+
+    // tagged words (obsolete, abbreviations, proper nouns, slang) that are kept out of the answers, e.g. --exclude obsolete,slang
+    // the policy is saved next to the cleaned word list once it is there, without --exclude the saved one is used
+    let exclude = match arg_value(&args, "--exclude").map(|list| ExclusionPolicy::parse(list)) {
+        Some(Some(policy)) => Some(policy),
+        Some(None) => {
+            println!(
+                "Unknown tag in --exclude, use obsolete, abbreviation, proper, slang, all or none"
+            );
+            return;
+        }
+        None => None,
+    };
 
     //check needed JSON files
-    match (raw_dictionary_path.exists(), clean_dictionary_path.exists()) {
+    match (
+        custom_source,
+        raw_dictionary_path.exists(),
+        definitions_path.exists()
+            && index_path.exists()
+            && accepted_guesses_path.exists()
+            && dictionary_dir.join(TAGS_FILE).exists()
+            && is_cleaned_from(language, &raw_dictionary_path),
+    ) {
+        //a custom word list is always cleaned so switching lists takes effect immediately
+        (Some(source), _, _) => {
+            println!("CLEANINING DICTIONARY FROM {:?}", source);
+            match clean(&source, language, language.word_length) {
+                Ok(_) => println!("Dictionary cleaned successfully."),
+                Err(e) => {
+                    println!("{}", e);
+                    return;
+                }
+            }
+        }
+
         //both do not exist so we download the raw file and clean it
-        (false, _) => {
+        (None, false, _) => {
+            let Some(url) = language.source_url else {
+                println!(
+                    "No word list for {}: place one at {:?} or pass --words <path>",
+                    language.name, raw_dictionary_path
+                );
+                return;
+            };
+
             println!("DOWNLOADING DICTIONARY FROM WEB");
-            match download_raw_dictionary(DICT_UTL, raw_dictionary_path) {
+            let options = DownloadOptions {
+                expected_sha256: language.source_sha256.map(String::from),
+                ..Default::default()
+            };
+            let mut report_progress = |downloaded: u64, total: Option<u64>| match total {
+                Some(total) if total > 0 => {
+                    print!(
+                        "\r{:>3}% ({} / {} bytes)",
+                        downloaded * 100 / total,
+                        downloaded,
+                        total
+                    );
+                    if downloaded == total {
+                        println!();
+                    }
+                    // the line is rewritten in place, so it has to be flushed without a newline
+                    let _ = io::stdout().flush();
+                }
+                _ => {
+                    print!("\r{} bytes", downloaded);
+                    let _ = io::stdout().flush();
+                }
+            };
+            if fs::create_dir_all(&language_dir).is_err() {
+                println!("Could not create {:?}", language_dir);
+                return;
+            }
+            match download_raw_dictionary(url, &raw_dictionary_path, &options, &mut report_progress)
+            {
                 Ok(_) => println!("DOWNLOAD COMPLETE"),
-                Err(e) => println!("{}", e),
+                Err(e) => {
+                    println!("{}", e);
+                    return;
+                }
             }
 
             println!("CLEANINING DICTIONARY");
-            match clean(WORLD_LENGTH) {
+            match clean(&raw_dictionary_path, language, language.word_length) {
                 Ok(_) => println!("Dictionary cleaned successfully."),
                 Err(e) => println!("{}", e),
             }
         }
 
-        //only the raw file exists so we clean it
-        (true, false) => {
+        //only the raw file exists (or the word list was cleaned before definitions were indexed and words were tagged
+        //or the cleaned dictionary was made from another word list with --words) so we clean it
+        (None, true, false) => {
             println!("CLEANINING DICTIONARY");
-            match clean(WORLD_LENGTH) {
+            match clean(&raw_dictionary_path, language, language.word_length) {
                 Ok(_) => println!("Dictionary cleaned successfully."),
                 Err(e) => println!("{}", e),
             }
         }
 
-        //only the raw file exists so we clean it
-        (true, true) => {}
+        //the cleaned dictionary was made from the raw file, so there is nothing to do
+        (None, true, true) => {}
     }
 
     // Else both dictionaries exist and we can proceed with the game
-    // Get the hashmap from the cleaned dictionary and pick a random word & defintion
-    let dict = match json_to_hashmap() {
+    // Get the words of the cleaned dictionary, their definitions are read when a game is over
+    let dict = match load_dictionary(language) {
         Ok(dict) => dict,
         Err(e) => {
             println!("{}", e);
@@ -58,5 +221,74 @@ fn main() {
         }
     };
 
-    MyEguiApp::run(&dict);
+    let mut accepted = match accepted_guesses(language) {
+        Ok(accepted) => accepted,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    // the daily schedule is made from every word of the cleaned dictionary, so the answer of a day only depends on the word list
+    let mut daily_schedule = DailySchedule::new(dict.words(), language);
+
+    // a new policy from --exclude replaces the saved one
+    let policy = match exclude {
+        Some(policy) => {
+            if let Err(e) = policy.save(language) {
+                println!("Error saving the excluded tags: {}", e);
+            }
+            policy
+        }
+        None => ExclusionPolicy::load(language),
+    };
+    let tags = match load_tags(language) {
+        Ok(tags) => tags,
+        Err(e) => {
+            println!("Error reading the word tags: {}", e);
+            Default::default()
+        }
+    };
+
+    // the answers are picked from the words of the cleaned dictionary, tagged words the policy excludes can still be guessed
+    let mut answers: Vec<String> = dict
+        .words()
+        .filter(|word| !tags.get(*word).is_some_and(|tags| policy.excludes(tags)))
+        .cloned()
+        .collect();
+
+    // blocked words are never the answer, with --accept-blocked-guesses they can still be guessed
+    Blocklist::load().apply(
+        &blocked_categories,
+        args.iter().any(|arg| arg == "--accept-blocked-guesses"),
+        language,
+        &mut answers,
+        &mut accepted,
+    );
+
+    // blocked and excluded words are skipped by the daily schedule, the player's own words don't change the daily answers
+    daily_schedule.skip_unless_answer(&answers);
+
+    // the player's own words: allowed words can be guessed and denied words are never the answer
+    match PersonalWords::load(language) {
+        Ok(personal) => personal.apply(language, &mut answers, &mut accepted),
+        Err(e) => println!("Error reading your personal words: {}", e),
+    }
+
+    // word frequencies are optional, without them the difficulty only looks at the letters
+    let frequencies = match load_word_frequencies(&dictionary_dir) {
+        Ok(frequencies) => frequencies,
+        Err(e) => {
+            println!("Error reading word frequencies: {}", e);
+            Default::default()
+        }
+    };
+
+    // the answers of the word length are tiled and sorted into the difficulties once, every game picks from them
+    let answers = AnswerPool::new(answers.iter(), language, language.word_length, &frequencies);
+
+    // --daily starts with today's daily puzzle
+    let daily = args.iter().any(|arg| arg == "--daily");
+
+    MyEguiApp::run(dict, answers, accepted, daily_schedule, daily, language);
 }
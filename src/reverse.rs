@@ -0,0 +1,115 @@
+use crate::game_logic::{score_guess, Candidate};
+use crate::language::Language;
+use crate::states::LetterState;
+use std::collections::HashSet;
+
+/// Enum for the outcome of a guess in reverse mode
+/// - Incomplete: the guess doesn't fill the row
+/// - NotAWord: the guess is not one of the accepted guesses
+/// - WrongPattern: the guess is a word, but it colors the row differently, these are the colors it gives
+/// - Match: the guess gives the colors of the row, the next row is played
+/// - Complete: the guess matched the last row
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReverseResult {
+    Incomplete,
+    NotAWord,
+    WrongPattern(Vec<LetterState>),
+    Match,
+    Complete,
+}
+
+#[derive(Debug, Clone)]
+/// This struct turns the wordle game around: the answer and the colors of every row are known
+/// and the player has to find a real word for each row that gets exactly those colors against the answer.
+/// The rows are played top to bottom, a row of only green squares is the answer itself and is filled in from the start.
+pub struct ReverseGame {
+    pub language: &'static Language,
+    pub answer: Candidate,
+    pub targets: Vec<Vec<LetterState>>,
+    /// the word found for each row, None while the row is open
+    pub found: Vec<Option<Vec<String>>>,
+}
+
+impl ReverseGame {
+    /// Constructor method for a reverse game, the targets need the length of the answer
+    pub fn new(
+        answer: String,
+        language: &'static Language,
+        targets: Vec<Vec<LetterState>>,
+    ) -> Self {
+        let answer = Candidate::new(answer, language);
+        let found = targets
+            .iter()
+            .map(|target| {
+                target
+                    .iter()
+                    .all(|&state| state == LetterState::Correct)
+                    .then(|| answer.tiles.clone())
+            })
+            .collect();
+
+        Self {
+            language,
+            answer,
+            targets,
+            found,
+        }
+    }
+
+    /// Method for the row that is played now, None once every row has its word
+    pub fn current_row(&self) -> Option<usize> {
+        self.found.iter().position(|found| found.is_none())
+    }
+
+    /// Method that checks a guess against the colors of the current row
+    pub fn submit_guess(
+        &mut self,
+        guess: &[String],
+        accepted_guesses: &HashSet<String>,
+    ) -> ReverseResult {
+        let Some(row) = self.current_row() else {
+            return ReverseResult::Complete;
+        };
+
+        if guess.len() != self.answer.tiles.len() || guess.iter().any(|tile| tile.is_empty()) {
+            return ReverseResult::Incomplete;
+        }
+        if !self
+            .language
+            .guess_key(guess)
+            .is_some_and(|key| accepted_guesses.contains(&key))
+        {
+            return ReverseResult::NotAWord;
+        }
+
+        let pattern = score_guess(guess, &self.answer.tiles);
+        if pattern != self.targets[row] {
+            return ReverseResult::WrongPattern(pattern);
+        }
+
+        self.found[row] = Some(guess.to_vec());
+        if self.current_row().is_some() {
+            ReverseResult::Match
+        } else {
+            ReverseResult::Complete
+        }
+    }
+
+    /// Method for the number of words that give the colors of a row, shown as a hint.
+    /// The words are the accepted guesses of the answer's length, already split into tiles.
+    pub fn solutions(&self, row: usize, words: &[Vec<String>]) -> usize {
+        words
+            .iter()
+            .filter(|tiles| score_guess(tiles, &self.answer.tiles) == self.targets[row])
+            .count()
+    }
+
+    /// Method to check if every row can be matched by one of the words
+    pub fn is_solvable(&self, words: &[Vec<String>]) -> bool {
+        self.targets.iter().all(|target| {
+            words
+                .iter()
+                .any(|tiles| score_guess(tiles, &self.answer.tiles) == *target)
+        })
+    }
+}
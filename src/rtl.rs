@@ -0,0 +1,110 @@
+/// Hebrew letters with the form they take at the end of a word
+const HEBREW_FINALS: [(char, char); 5] =
+    [('כ', 'ך'), ('מ', 'ם'), ('נ', 'ן'), ('פ', 'ף'), ('צ', 'ץ')];
+
+/// Arabic letters with the first of their presentation forms and whether they join the letter after them.
+/// The forms follow each other as isolated, final, initial and medial, letters that don't join the next letter only have the first two.
+const ARABIC_FORMS: [(char, u32, bool); 28] = [
+    ('ا', 0xFE8D, false),
+    ('ب', 0xFE8F, true),
+    ('ت', 0xFE95, true),
+    ('ث', 0xFE99, true),
+    ('ج', 0xFE9D, true),
+    ('ح', 0xFEA1, true),
+    ('خ', 0xFEA5, true),
+    ('د', 0xFEA9, false),
+    ('ذ', 0xFEAB, false),
+    ('ر', 0xFEAD, false),
+    ('ز', 0xFEAF, false),
+    ('س', 0xFEB1, true),
+    ('ش', 0xFEB5, true),
+    ('ص', 0xFEB9, true),
+    ('ض', 0xFEBD, true),
+    ('ط', 0xFEC1, true),
+    ('ظ', 0xFEC5, true),
+    ('ع', 0xFEC9, true),
+    ('غ', 0xFECD, true),
+    ('ف', 0xFED1, true),
+    ('ق', 0xFED5, true),
+    ('ك', 0xFED9, true),
+    ('ل', 0xFEDD, true),
+    ('م', 0xFEE1, true),
+    ('ن', 0xFEE5, true),
+    ('ه', 0xFEE9, true),
+    ('و', 0xFEED, false),
+    ('ي', 0xFEF1, true),
+];
+
+/// Helper function to find the presentation forms of a tile that is a single Arabic letter
+fn arabic_letter(tile: &str) -> Option<(char, u32, bool)> {
+    let mut chars = tile.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => ARABIC_FORMS.iter().copied().find(|(base, _, _)| *base == c),
+        _ => None,
+    }
+}
+
+/// Function that turns a letter written in a positional form back into its base letter.
+/// Hebrew final letters (ם -> מ) and Arabic presentation forms (ﺑ -> ب) are scored as the base letter,
+/// as are the Arabic word endings ة and ى that are played as ه and ي.
+pub fn base_letter(c: char) -> char {
+    if let Some((base, _)) = HEBREW_FINALS.iter().find(|(_, last)| *last == c) {
+        return *base;
+    }
+
+    let code = c as u32;
+    for (base, first, joins_next) in ARABIC_FORMS {
+        let forms = if joins_next { 4 } else { 2 };
+        if (first..first + forms).contains(&code) {
+            return base;
+        }
+    }
+
+    match c {
+        'ة' => 'ه',
+        'ى' => 'ي',
+        _ => c,
+    }
+}
+
+/// Function for the form of the Hebrew tile at a position, letters with a final form take it at the end of the word
+pub fn hebrew_form(tiles: &[String], index: usize) -> String {
+    let tile = &tiles[index];
+    if index + 1 != tiles.len() {
+        return tile.clone();
+    }
+
+    match HEBREW_FINALS
+        .iter()
+        .find(|(base, _)| base.to_string() == *tile)
+    {
+        Some((_, last)) => last.to_string(),
+        None => tile.clone(),
+    }
+}
+
+/// Function for the form of the Arabic tile at a position.
+/// A letter joins the letter before it if that one connects forward, and the letter after it if it connects forward itself,
+/// which gives its isolated, final, initial or medial form.
+pub fn arabic_form(tiles: &[String], index: usize) -> String {
+    let Some((_, first, joins_next)) = arabic_letter(&tiles[index]) else {
+        return tiles[index].clone();
+    };
+
+    let joined_before = index > 0 && matches!(arabic_letter(&tiles[index - 1]), Some((_, _, true)));
+    let joined_after = joins_next
+        && tiles
+            .get(index + 1)
+            .and_then(|t| arabic_letter(t))
+            .is_some();
+    let offset = match (joined_before, joined_after) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (true, true) => 3,
+    };
+
+    char::from_u32(first + offset)
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| tiles[index].clone())
+}
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Enum for the letters in the wordle game.
 /// A letter can be:
 /// - Correct: if present and in the right place.
@@ -13,7 +14,7 @@ pub enum LetterState {
     Unknown,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Enum used for showing differnt messages based on the game state
 /// - CorrectGuess: the guess was accepted, but it was not the right word
 /// - WrongGuess: the guess was not accepted
@@ -37,3 +38,52 @@ impl fmt::Display for GameState {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Enum for the way the answer of a game is picked
+/// - Random: a random word of the chosen difficulty, restarting picks a new one
+/// - Daily: the answer of a day from the daily schedule, every date can be played once.
+///   Archive puzzles are past days played later, they are recorded apart from the puzzles played on their date.
+/// - Custom: a puzzle a player made and shared as a code
+/// - Absurdle: no answer is picked, the game dodges every guess until a single word is left
+/// - MultiBoard: several boards with a random word each are played at once (Dordle, Quordle, ...)
+/// - Timed: as many random words as possible before the minutes are up
+/// - SpeedRun: a single random word against the stopwatch, every guess adds a penalty
+/// - Marathon: random words one after the other, unused attempts carry over until a word isn't solved
+/// - Reverse: the answer and the colors of every row are given, the player finds a word for each row
+/// - Mastermind: a code of symbols (digits, colors, ...) instead of a word, any combination can be guessed
+/// - HotSeat: two players on one machine, one sets the word and the other guesses it
+pub enum GameMode {
+    Random,
+    Daily { day: u64, archive: bool },
+    Custom,
+    Absurdle,
+    MultiBoard { boards: usize },
+    Timed { minutes: u64 },
+    SpeedRun,
+    Marathon,
+    Reverse,
+    Mastermind,
+    HotSeat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Enum for the difficulty of the word that has to be guessed
+/// - Easy: common words made of common letters without repeated letters
+/// - Normal: any word from the dictionary
+/// - Hard: rare words, words with repeated letters or awkward letter patterns
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "Easy"),
+            Difficulty::Normal => write!(f, "Normal"),
+            Difficulty::Hard => write!(f, "Hard"),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::language::{DiacriticMode, Language, LetterForms, TileScheme};
+use rand::seq::SliceRandom;
+use std::sync::Mutex;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Number of symbols of a Mastermind code
+pub const CODE_LENGTH: usize = 4;
+
+/// Helper function for a symbol set: a pack that is played like a language but has no word list,
+/// so every combination of its symbols is a guess
+const fn symbol_set(
+    code: &'static str,
+    name: &'static str,
+    alphabet: &'static [&'static str],
+) -> Language {
+    Language {
+        code,
+        name,
+        alphabet,
+        accented_letters: &[],
+        diacritics: DiacriticMode::Distinct,
+        digraphs: &[],
+        tile_scheme: TileScheme::Graphemes,
+        rtl: false,
+        letter_forms: LetterForms::Plain,
+        word_length: CODE_LENGTH,
+        keymap: &[],
+        fonts: &[],
+        source: "",
+        source_url: None,
+        source_sha256: None,
+        inflections: false,
+    }
+}
+
+pub const DIGITS: Language = symbol_set(
+    "digits",
+    "Digits",
+    &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+);
+
+pub const COLORS: Language = symbol_set("colors", "Colors", &["🔴", "🟠", "🟡", "🟢", "🔵", "🟣"]);
+
+pub const EMOJI: Language = symbol_set(
+    "emoji",
+    "Emoji",
+    &["🍎", "🍌", "🍇", "🍒", "🍋", "🍉", "🥝", "🍑"],
+);
+
+pub const SYMBOL_SETS: &[&Language] = &[&DIGITS, &COLORS, &EMOJI];
+
+/// Symbol sets the players made so far, a set that is entered again is taken from here
+static CUSTOM_SETS: Mutex<Vec<&'static Language>> = Mutex::new(Vec::new());
+
+/// Function that makes a symbol set of the symbols a player entered, separated by spaces.
+/// Symbols are written the way tiles are (composed and upper case), a symbol of several letters is a single tile.
+/// The set lives for the rest of the program, just like the built in ones. It is only made once,
+/// playing the same symbols again uses the set that was made before.
+pub fn custom_symbols(text: &str) -> Result<&'static Language, String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for symbol in text.split_whitespace() {
+        let symbol = symbol.nfc().collect::<String>().to_uppercase();
+        if !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    if symbols.len() < 2 {
+        return Err("Enter at least two symbols, separated by spaces".to_string());
+    }
+
+    let mut sets = CUSTOM_SETS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(set) = sets
+        .iter()
+        .find(|set| set.alphabet.iter().eq(symbols.iter()))
+    {
+        return Ok(set);
+    }
+
+    let symbols: Vec<&'static str> = symbols
+        .into_iter()
+        .map(|symbol| &*Box::leak(symbol.into_boxed_str()))
+        .collect();
+    let digraphs: Vec<&'static str> = symbols
+        .iter()
+        .copied()
+        .filter(|symbol| symbol.graphemes(true).count() > 1)
+        .collect();
+
+    let set: &'static Language = Box::leak(Box::new(Language {
+        digraphs: Box::leak(digraphs.into_boxed_slice()),
+        ..symbol_set("custom", "Custom", Box::leak(symbols.into_boxed_slice()))
+    }));
+    sets.push(set);
+    Ok(set)
+}
+
+/// Function for a random code of a symbol set, a symbol can appear more than once
+pub fn random_code(symbols: &Language) -> String {
+    let mut rng = rand::thread_rng();
+    (0..symbols.word_length)
+        .filter_map(|_| symbols.alphabet.choose(&mut rng))
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_sets_are_made_once() {
+        let first = custom_symbols("♠ ♥ ♦ ♣").unwrap();
+        let again = custom_symbols("♠  ♥ ♦ ♣ ♠").unwrap();
+        assert!(std::ptr::eq(first, again));
+        assert!(!std::ptr::eq(first, custom_symbols("♠ ♥").unwrap()));
+        assert!(custom_symbols("x").is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::language::Language;
+use crate::utils::storage::data_dir;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+
+/// The blocklist that ships with the game
+const BUNDLED_BLOCKLIST: &str = include_str!("blocklist.txt");
+
+/// Categories of blocked words
+/// - Offensive: slurs and insults
+/// - Sensitive: words about violence and other topics that are upsetting out of context
+/// - Adult: sexual words
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockCategory {
+    Offensive,
+    Sensitive,
+    Adult,
+}
+
+impl BlockCategory {
+    /// All categories, in the order they are listed
+    pub const ALL: [BlockCategory; 3] = [
+        BlockCategory::Offensive,
+        BlockCategory::Sensitive,
+        BlockCategory::Adult,
+    ];
+
+    /// Helper function to find a category by its name
+    pub fn from_name(name: &str) -> Option<BlockCategory> {
+        BlockCategory::ALL
+            .into_iter()
+            .find(|category| category.to_string().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Helper function that reads a comma separated list of categories, e.g. "offensive,adult".
+    /// "all" blocks every category and "none" turns the filter off. Returns None for an unknown category.
+    pub fn parse_list(list: &str) -> Option<Vec<BlockCategory>> {
+        match list.trim() {
+            "all" => Some(BlockCategory::ALL.to_vec()),
+            "none" => Some(Vec::new()),
+            list => list.split(',').map(BlockCategory::from_name).collect(),
+        }
+    }
+}
+
+impl fmt::Display for BlockCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlockCategory::Offensive => write!(f, "offensive"),
+            BlockCategory::Sensitive => write!(f, "sensitive"),
+            BlockCategory::Adult => write!(f, "adult"),
+        }
+    }
+}
+
+/// Struct for the words that are kept out of the answer pool, with the category they are blocked for
+#[derive(Debug, Clone, Default)]
+pub struct Blocklist {
+    words: HashMap<String, BlockCategory>,
+}
+
+impl Blocklist {
+    /// Helper function that reads the bundled blocklist and applies the player's blocklist from the user data directory on top
+    pub fn load() -> Self {
+        let mut blocklist = Blocklist::default();
+        blocklist.read(BUNDLED_BLOCKLIST);
+
+        let path = data_dir().join("blocklist.txt");
+        if path.exists() {
+            match fs::read_to_string(&path) {
+                Ok(text) => blocklist.read(&text),
+                Err(e) => println!("Error reading {:?}: {}", path, e),
+            }
+        }
+
+        blocklist
+    }
+
+    /// Method that reads a blocklist in the bundled format.
+    /// A "[category]" line starts the words of a category, every following line is a word,
+    /// a word starting with '!' is unblocked and lines starting with '#' are comments.
+    pub fn read(&mut self, text: &str) {
+        let mut category = BlockCategory::Offensive;
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                match BlockCategory::from_name(name) {
+                    Some(c) => category = c,
+                    None => println!("Unknown blocklist category {}", name),
+                }
+            } else if let Some(word) = line.strip_prefix('!') {
+                self.words.remove(&word.trim().to_lowercase());
+            } else {
+                self.words.insert(line.to_lowercase(), category);
+            }
+        }
+    }
+
+    /// Method that removes the words of the blocked categories from the answers.
+    /// Unless blocked words may still be guessed, they are removed from the accepted guesses as well.
+    pub fn apply(
+        &self,
+        categories: &[BlockCategory],
+        accept_guesses: bool,
+        language: &Language,
+        answers: &mut Vec<String>,
+        accepted_guesses: &mut HashSet<String>,
+    ) {
+        let blocked: HashSet<String> = self
+            .words
+            .iter()
+            .filter(|(_, category)| categories.contains(category))
+            .map(|(word, _)| language.key(word))
+            .collect();
+
+        answers.retain(|word| !blocked.contains(&language.key(word)));
+        if !accept_guesses {
+            accepted_guesses.retain(|key| !blocked.contains(key));
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use crate::language::Language;
+use crate::utils::inflection::strip_labels;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+
+/// File next to the cleaned dictionary with the tags of every tagged word, one "word tag,tag" line per word
+pub const TAGS_FILE: &str = "word_tags.txt";
+
+/// File next to the cleaned dictionary with the tags that are kept out of the answers
+const POLICY_FILE: &str = "exclude.txt";
+
+/// Labels Webster uses for senses that are no longer in use
+const OBSOLETE_MARKERS: &[&str] = &["Obs.", "Obsolete", "Archaic"];
+
+/// Labels Webster uses for slang senses
+const SLANG_MARKERS: &[&str] = &["Slang", "[Cant]", "(Cant)"];
+
+/// Beginnings of definitions that expand an abbreviation
+const ABBREVIATION_PREFIXES: &[&str] = &[
+    "Abbr.",
+    "Abbreviation",
+    "An abbreviation",
+    "A contraction",
+    "Contraction",
+];
+
+/// Beginnings of definitions that name a person or a place instead of describing a word
+const PROPER_NOUN_PREFIXES: &[&str] = &[
+    "A city ",
+    "A town ",
+    "A village ",
+    "A river ",
+    "A country ",
+    "A county ",
+    "A province ",
+    "A state of ",
+    "An island ",
+    "A mountain ",
+    "The capital ",
+    "The name of ",
+    "A proper name",
+    "A surname",
+    "A masculine name",
+    "A feminine name",
+];
+
+/// Tags for dictionary entries that make poor answers
+/// - Obsolete: every sense of the word is marked obsolete or archaic
+/// - Abbreviation: the word is an abbreviation or contraction
+/// - ProperNoun: the word names a person or a place
+/// - Slang: every sense of the word is marked as slang
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordTag {
+    Obsolete,
+    Abbreviation,
+    ProperNoun,
+    Slang,
+}
+
+impl WordTag {
+    /// All tags, in the order they are listed
+    pub const ALL: [WordTag; 4] = [
+        WordTag::Obsolete,
+        WordTag::Abbreviation,
+        WordTag::ProperNoun,
+        WordTag::Slang,
+    ];
+
+    /// Helper function to find a tag by the name used on the command line
+    pub fn from_name(name: &str) -> Option<WordTag> {
+        WordTag::ALL
+            .into_iter()
+            .find(|tag| tag.to_string().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl fmt::Display for WordTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WordTag::Obsolete => write!(f, "obsolete"),
+            WordTag::Abbreviation => write!(f, "abbreviation"),
+            WordTag::ProperNoun => write!(f, "proper"),
+            WordTag::Slang => write!(f, "slang"),
+        }
+    }
+}
+
+/// Struct for the tags that keep a word out of the answer pool.
+/// Excluded words stay in the cleaned dictionary and the accepted guesses, so they can be guessed and have a definition but are never picked as the answer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExclusionPolicy {
+    pub excluded: Vec<WordTag>,
+}
+
+impl Default for ExclusionPolicy {
+    /// Obsolete words, abbreviations and proper nouns are excluded by default, slang words can be answers
+    fn default() -> Self {
+        ExclusionPolicy {
+            excluded: vec![
+                WordTag::Obsolete,
+                WordTag::Abbreviation,
+                WordTag::ProperNoun,
+            ],
+        }
+    }
+}
+
+impl ExclusionPolicy {
+    /// Helper function that reads a policy from a comma separated list of tags, e.g. "obsolete,slang".
+    /// "none" keeps every word and "all" excludes every tag. Returns None for an unknown tag.
+    pub fn parse(list: &str) -> Option<ExclusionPolicy> {
+        match list.trim() {
+            "none" => Some(ExclusionPolicy {
+                excluded: Vec::new(),
+            }),
+            "all" => Some(ExclusionPolicy {
+                excluded: WordTag::ALL.to_vec(),
+            }),
+            list => list
+                .split(',')
+                .map(WordTag::from_name)
+                .collect::<Option<Vec<WordTag>>>()
+                .map(|excluded| ExclusionPolicy { excluded }),
+        }
+    }
+
+    /// Method that checks if a word with the given tags is kept out of the answer pool
+    pub fn excludes(&self, tags: &[WordTag]) -> bool {
+        tags.iter().any(|tag| self.excluded.contains(tag))
+    }
+
+    /// Helper function that reads the policy saved next to the cleaned dictionary of the language,
+    /// the default policy is used until one is saved
+    pub fn load(language: &Language) -> ExclusionPolicy {
+        match fs::read_to_string(language.dictionary_dir().join(POLICY_FILE)) {
+            Ok(list) => ExclusionPolicy::parse(&list).unwrap_or_else(|| {
+                println!("Unknown tag in {}, using the default tags", POLICY_FILE);
+                ExclusionPolicy::default()
+            }),
+            Err(_) => ExclusionPolicy::default(),
+        }
+    }
+
+    /// Method that saves the policy next to the cleaned dictionary of the language, it is used on every start from then on
+    pub fn save(&self, language: &Language) -> io::Result<()> {
+        fs::write(
+            language.dictionary_dir().join(POLICY_FILE),
+            self.to_string(),
+        )
+    }
+}
+
+impl fmt::Display for ExclusionPolicy {
+    /// The policy is written the way it is passed to --exclude, e.g. "obsolete,slang"
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.excluded.is_empty() {
+            return write!(f, "none");
+        }
+        let tags: Vec<String> = self.excluded.iter().map(|tag| tag.to_string()).collect();
+        write!(f, "{}", tags.join(","))
+    }
+}
+
+/// Function that reads the tags the cleaner wrote for the words of the cleaned dictionary, untagged words are not listed
+pub fn load_tags(language: &Language) -> io::Result<HashMap<String, Vec<WordTag>>> {
+    let mut tags = HashMap::new();
+    let path = language.dictionary_dir().join(TAGS_FILE);
+    if !path.exists() {
+        return Ok(tags);
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    for line in reader.lines() {
+        let line = line?;
+        if let Some((word, list)) = line.split_once(' ') {
+            let word_tags: Vec<WordTag> = list.split(',').filter_map(WordTag::from_name).collect();
+            tags.insert(word.to_string(), word_tags);
+        }
+    }
+
+    Ok(tags)
+}
+
+/// Helper function that splits a definition into its numbered senses ("1. ...  2. ...").
+/// A sense marker is a number followed by a dot at the start or after whitespace, and the numbers have to count up from 1,
+/// so other numbers in the text (a year at the end of a sentence, "2.5 inches") don't split a sense.
+fn senses(definition: &str) -> Vec<&str> {
+    let bytes = definition.as_bytes();
+    let mut senses = Vec::new();
+    let mut sense_start = 0;
+    let mut next_number = 1;
+    let mut i = 0;
+    while i < bytes.len() {
+        let at_word_start = i == 0 || bytes[i - 1].is_ascii_whitespace();
+        if !bytes[i].is_ascii_digit() || !at_word_start {
+            i += 1;
+            continue;
+        }
+
+        let digits_end = i + bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let is_marker = bytes.get(digits_end) == Some(&b'.')
+            && bytes
+                .get(digits_end + 1)
+                .is_none_or(|b| b.is_ascii_whitespace())
+            && definition[i..digits_end].parse::<usize>() == Ok(next_number);
+        if is_marker {
+            senses.push(&definition[sense_start..i]);
+            sense_start = digits_end + 1;
+            next_number += 1;
+        }
+        i = digits_end;
+    }
+    senses.push(&definition[sense_start..]);
+
+    senses
+        .into_iter()
+        .map(str::trim)
+        .filter(|sense| !sense.is_empty())
+        .collect()
+}
+
+/// Function that tags a dictionary entry from its definition text.
+/// Obsolete and slang words need the label on every sense, so a word with one old sense among current ones is kept.
+/// Abbreviations and proper nouns are recognised by how the first sense begins ("Abbr. of ...", "A city in ...").
+/// Entries without a definition (plain word lists) get no tags.
+pub fn classify(definition: &str) -> Vec<WordTag> {
+    let mut tags = Vec::new();
+    let senses = senses(definition);
+    if senses.is_empty() {
+        return tags;
+    }
+
+    let all_marked = |markers: &[&str]| {
+        senses
+            .iter()
+            .all(|sense| markers.iter().any(|marker| sense.contains(marker)))
+    };
+    if all_marked(OBSOLETE_MARKERS) {
+        tags.push(WordTag::Obsolete);
+    }
+
+    // the labels in front of the first sense are kept for the abbreviation check ("Abbr." can be written as a label)
+    let first = senses[0];
+    if ABBREVIATION_PREFIXES
+        .iter()
+        .any(|prefix| first.starts_with(prefix) || strip_labels(first).starts_with(prefix))
+    {
+        tags.push(WordTag::Abbreviation);
+    }
+
+    if PROPER_NOUN_PREFIXES
+        .iter()
+        .any(|prefix| strip_labels(first).starts_with(prefix))
+    {
+        tags.push(WordTag::ProperNoun);
+    }
+
+    if all_marked(SLANG_MARKERS) {
+        tags.push(WordTag::Slang);
+    }
+
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn senses_are_split_on_their_numbers() {
+        assert_eq!(
+            senses("1. A small boat. 2. (Obs.) A sail. 3. Measuring 2.5 feet"),
+            vec!["A small boat.", "(Obs.) A sail.", "Measuring 2.5 feet"]
+        );
+        assert_eq!(senses("A plain definition"), vec!["A plain definition"]);
+    }
+
+    #[test]
+    fn years_do_not_split_a_sense() {
+        assert_eq!(
+            senses("1. A city founded in 1850. 2. A coin struck in 1794 and 1795."),
+            vec!["A city founded in 1850.", "A coin struck in 1794 and 1795."]
+        );
+        assert_eq!(
+            senses("A city founded in 1850. Its river"),
+            vec!["A city founded in 1850. Its river"]
+        );
+    }
+}
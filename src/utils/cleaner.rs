@@ -1,52 +1,344 @@
+use crate::language::Language;
+use crate::utils::classifier::{classify, WordTag, TAGS_FILE};
+use crate::utils::definition::Definition;
 use crate::utils::errors::DictionaryError;
+use crate::utils::frequency::SOURCE_FREQUENCY_FILE;
+use crate::utils::inflection::inflected_forms;
+use crate::utils::loader::{loader_for_path, DictionaryEntry};
+use crate::utils::store::{DEFINITIONS_FILE, INDEX_FILE};
 use rayon::prelude::*;
 use serde_json::to_writer_pretty;
-use std::collections::{BTreeMap, HashMap};
-use std::fs::File;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs::{self, File};
 use std::io::BufReader;
-use std::io::BufWriter;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
-/// Helper function to create a new "cleaned" JSON dictionary that only contains n-letter words.
+/// Number of source entries that are inflected and filtered together in parallel before they are written
+const CHUNK_SIZE: usize = 4096;
+
+/// File next to the cleaned dictionary that names the word list it was cleaned from
+const SOURCE_MARKER: &str = "source.txt";
+
+/// Helper function to create a new "cleaned" dictionary that only contains n-letter words.
 /// For the basic Wordle game, this is going to be 5-letter words; however, I kept it flexible in case someone wants to play with longer words.
+/// The source can be any format understood by the loaders (Webster JSON, Wiktionary JSON lines, plain text, CSV or TSV).
+/// Next to the dictionary it writes the accepted guesses: every kept word plus the inflected forms of all source words
+/// (plurals, past tenses, ...) that fit the word length, so validating a guess is a plain lookup.
+/// If the source comes with word frequencies, these are written to the source frequency file next to the cleaned dictionary,
+/// otherwise the source frequency file of an earlier word list is removed. The player's own frequency file is never touched.
+/// The source is recorded as well, so a dictionary cleaned from another word list is noticed and cleaned again.
+/// Word lengths are counted in tiles of the language and words with letters outside its alphabet are dropped.
+/// Entries are tagged from their definition (obsolete, abbreviation, proper noun, slang) and the tags are written to the tags file,
+/// the game keeps words out of the answers by their tags when it starts, so changing the policy doesn't need a new clean.
+/// Definitions are written with their senses (part of speech, labels, notes and cross-references)
+/// to a definitions file with an offset table, so they can be read one at a time.
+/// All files are written to the directory of the language.
+///
+/// The source is streamed: entries are read in chunks and the kept words are written as they come,
+/// so only the playable words are held in memory, however large the source is.
+/// Words appear in the order of the source and the first entry of a word that is listed twice is kept.
 /// This function returns a Result, either Ok when the dictionary was cleaned successfully or Err in case issues arise.
-pub fn clean(word_length: usize) -> Result<(), DictionaryError> {
-    // raw_dictionary should exist at this path and the file should be opened
-    let file = match File::open("src/utils/raw_dictionary.json") {
+pub fn clean(
+    source: &Path,
+    language: &Language,
+    word_length: usize,
+) -> Result<(), DictionaryError> {
+    // the source dictionary should exist at this path and the file should be opened
+    let file = match File::open(source) {
         Ok(f) => f,
         Err(_) => {
             return Err(DictionaryError::PathError);
         }
     };
 
-    // create a reader and deserialize the JSON dictionary into a hashmap
-    let reader = BufReader::new(file);
-    let dictionary: HashMap<String, String> = match serde_json::from_reader(reader) {
-        Ok(dict) => dict,
+    let output_dir = language.dictionary_dir();
+    fs::create_dir_all(&output_dir).map_err(|_| DictionaryError::PathError)?;
+
+    let mut cleaner = StreamingCleaner {
+        language,
+        word_length,
+        chunk: Vec::with_capacity(CHUNK_SIZE),
+        accepted_guesses: BTreeSet::new(),
+        frequencies: BTreeMap::new(),
+        tags: BTreeMap::new(),
+        written: HashSet::new(),
+        writer: DictionaryWriter::create(&output_dir)?,
+    };
+
+    // create a reader and let the loader for the source format hand over the entries one by one
+    let mut reader = BufReader::new(file);
+    loader_for_path(source).load(&mut reader, &mut |entry| cleaner.add(entry))?;
+    cleaner.flush_chunk()?;
+
+    let StreamingCleaner {
+        accepted_guesses,
+        frequencies,
+        tags,
+        writer,
+        ..
+    } = cleaner;
+    writer.finish()?;
+    write_accepted_guesses(&output_dir, &accepted_guesses)?;
+    write_tags(&output_dir, &tags)?;
+    if frequencies.is_empty() {
+        match fs::remove_file(output_dir.join(SOURCE_FREQUENCY_FILE)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(DictionaryError::PathError)
+            }
+            _ => {}
+        }
+    } else {
+        write_frequencies(&output_dir, &frequencies)?;
+    }
+    fs::write(output_dir.join(SOURCE_MARKER), source_name(source))
+        .map_err(|_| DictionaryError::PathError)?;
+
+    Ok(())
+}
+
+/// Helper function for the name a word list is recorded with, its full path if it can be resolved
+fn source_name(source: &Path) -> String {
+    fs::canonicalize(source)
+        .unwrap_or_else(|_| source.to_path_buf())
+        .display()
+        .to_string()
+}
+
+/// Function to check if the cleaned dictionary of the language was cleaned from the given word list.
+/// A dictionary without a record of its source was cleaned by an older version and counts as another list.
+pub fn is_cleaned_from(language: &Language, source: &Path) -> bool {
+    fs::read_to_string(language.dictionary_dir().join(SOURCE_MARKER))
+        .is_ok_and(|recorded| recorded == source_name(source))
+}
+
+/// Struct for the state of a running clean.
+/// Everything it keeps only holds words that fit the word length, the source entries are dropped once their chunk is written.
+struct StreamingCleaner<'a> {
+    language: &'a Language,
+    word_length: usize,
+    /// entries read from the source that are not processed yet
+    chunk: Vec<DictionaryEntry>,
+    accepted_guesses: BTreeSet<String>,
+    frequencies: BTreeMap<String, f64>,
+    tags: BTreeMap<String, Vec<WordTag>>,
+    /// words already written to the cleaned dictionary
+    written: HashSet<String>,
+    writer: DictionaryWriter,
+}
+
+impl StreamingCleaner<'_> {
+    /// Method that collects an entry and processes the chunk once it is full
+    fn add(&mut self, entry: DictionaryEntry) -> Result<(), DictionaryError> {
+        self.chunk.push(entry);
+        if self.chunk.len() >= CHUNK_SIZE {
+            self.flush_chunk()?;
+        }
+        Ok(())
+    }
+
+    /// Method that processes the collected entries in parallel using rayon and writes the kept words
+    fn flush_chunk(&mut self) -> Result<(), DictionaryError> {
+        let chunk = std::mem::replace(&mut self.chunk, Vec::with_capacity(CHUNK_SIZE));
+        let (language, word_length) = (self.language, self.word_length);
+
+        // generate the inflected forms from all lemmas, as a short form can come from a long lemma (choose -> chose)
+        let guesses: Vec<String> = chunk
+            .par_iter()
+            .flat_map_iter(|entry| {
+                let mut forms = if language.inflections {
+                    inflected_forms(&entry.word, &entry.definition)
+                } else {
+                    Vec::new()
+                };
+                forms.push(entry.word.clone());
+                forms
+            })
+            .filter(|word| language.is_playable(word, word_length))
+            .map(|word| language.key(&word))
+            .collect();
+        self.accepted_guesses.extend(guesses);
+
+        // filter the entries for words of the specified length and tag them
+        let kept: Vec<(DictionaryEntry, Vec<WordTag>)> = chunk
+            .into_par_iter()
+            .filter(|entry| language.is_playable(&entry.word, word_length))
+            .map(|entry| {
+                let tags = classify(&entry.definition);
+                (entry, tags)
+            })
+            .collect();
+
+        for (entry, tags) in kept {
+            if !self.written.insert(entry.word.clone()) {
+                continue;
+            }
+            if let Some(frequency) = entry.frequency {
+                self.frequencies.insert(entry.word.clone(), frequency);
+            }
+            if !tags.is_empty() {
+                self.tags.insert(entry.word.clone(), tags);
+            }
+            self.writer.write_entry(&entry.word, &entry.structured)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Struct that writes the cleaned dictionary one entry at a time.
+/// Every definition goes on its own line of the definitions file and the offset table records where it starts,
+/// so the game only loads the words and reads a definition when it needs it.
+/// Both files are written to temporary files that replace the dictionary once it is complete, so an interrupted clean leaves no half file behind.
+struct DictionaryWriter {
+    definitions: BufWriter<File>,
+    index: BufWriter<File>,
+    output_dir: PathBuf,
+    offset: u64,
+}
+
+/// Helper function for the temporary file a dictionary file is written to
+fn part_path(output_dir: &Path, name: &str) -> PathBuf {
+    output_dir.join(format!("{}.part", name))
+}
+
+impl DictionaryWriter {
+    /// Helper function to open the temporary files
+    fn create(output_dir: &Path) -> Result<DictionaryWriter, DictionaryError> {
+        let open = |name: &str| match File::create(part_path(output_dir, name)) {
+            Ok(f) => Ok(BufWriter::new(f)),
+            Err(_) => Err(DictionaryError::PathError),
+        };
+
+        Ok(DictionaryWriter {
+            definitions: open(DEFINITIONS_FILE)?,
+            index: open(INDEX_FILE)?,
+            output_dir: output_dir.to_path_buf(),
+            offset: 0,
+        })
+    }
+
+    /// Method that writes the definition of a word as the next line and records its offset
+    fn write_entry(&mut self, word: &str, definition: &Definition) -> Result<(), DictionaryError> {
+        let line = serde_json::to_string(definition).map_err(|_| DictionaryError::ParseError)?;
+
+        writeln!(self.definitions, "{}", line).map_err(|_| DictionaryError::PathError)?;
+        writeln!(self.index, "{}\t{}\t{}", word, self.offset, line.len())
+            .map_err(|_| DictionaryError::PathError)?;
+        self.offset += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    /// Method that moves the finished files into place, the offset table last as it marks the dictionary as complete
+    fn finish(mut self) -> Result<(), DictionaryError> {
+        self.definitions
+            .flush()
+            .map_err(|_| DictionaryError::PathError)?;
+        self.index.flush().map_err(|_| DictionaryError::PathError)?;
+
+        for name in [DEFINITIONS_FILE, INDEX_FILE] {
+            fs::rename(
+                part_path(&self.output_dir, name),
+                self.output_dir.join(name),
+            )
+            .map_err(|_| DictionaryError::PathError)?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper function to write the accepted guesses as a sorted JSON list
+fn write_accepted_guesses(
+    output_dir: &Path,
+    accepted_guesses: &BTreeSet<String>,
+) -> Result<(), DictionaryError> {
+    let output_file = match File::create(output_dir.join("accepted_guesses.json")) {
+        Ok(f) => f,
         Err(_) => {
-            return Err(DictionaryError::ParseError);
+            return Err(DictionaryError::PathError);
         }
     };
 
-    // filter the hashmap for words of the specified length in parallel using rayon
-    let filtered_words: BTreeMap<String, String> = dictionary
-        .into_par_iter()
-        .filter(|(word, _)| word.len() <= word_length && !word.contains("-"))
-        .collect::<BTreeMap<_, _>>(); // Collect into a BTreeMap to maintain order
+    let writer = BufWriter::new(output_file);
+    to_writer_pretty(writer, accepted_guesses).map_err(|_| DictionaryError::ParseError)
+}
 
-    // create the output file
-    let output_file = match File::create("src/utils/clean_dictionary.json") {
+/// Helper function to write the tags of the tagged words as "word tag,tag" lines
+fn write_tags(
+    output_dir: &Path,
+    tags: &BTreeMap<String, Vec<WordTag>>,
+) -> Result<(), DictionaryError> {
+    let output_file = match File::create(output_dir.join(TAGS_FILE)) {
         Ok(f) => f,
         Err(_) => {
             return Err(DictionaryError::PathError);
         }
     };
 
-    // Create a buffered writer and write the filtered dictionary to the output file
-    let writer = BufWriter::new(output_file);
-    match to_writer_pretty(writer, &filtered_words) {
-        Ok(_) => Ok(()),
+    let mut writer = BufWriter::new(output_file);
+    for (word, word_tags) in tags {
+        let word_tags: Vec<String> = word_tags.iter().map(|tag| tag.to_string()).collect();
+        writeln!(writer, "{} {}", word, word_tags.join(","))
+            .map_err(|_| DictionaryError::PathError)?;
+    }
+
+    writer.flush().map_err(|_| DictionaryError::PathError)
+}
+
+/// Helper function to write the word frequencies of the source as "word frequency" lines
+fn write_frequencies(
+    output_dir: &Path,
+    frequencies: &BTreeMap<String, f64>,
+) -> Result<(), DictionaryError> {
+    let output_file = match File::create(output_dir.join(SOURCE_FREQUENCY_FILE)) {
+        Ok(f) => f,
         Err(_) => {
-            return Err(DictionaryError::ParseError);
+            return Err(DictionaryError::PathError);
+        }
+    };
+
+    let mut writer = BufWriter::new(output_file);
+    for (word, frequency) in frequencies {
+        writeln!(writer, "{} {}", word, frequency).map_err(|_| DictionaryError::PathError)?;
+    }
+
+    writer.flush().map_err(|_| DictionaryError::PathError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::store::DefinitionStore;
+
+    #[test]
+    fn definitions_are_read_back_by_their_offset() {
+        let dir = std::env::temp_dir().join(format!("rustywordle-store-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // definitions with letters of several bytes, the offsets count bytes
+        let entries = [
+            (
+                "abode",
+                Definition::parse("1. A dwelling. 2. (Obs.) A stay."),
+            ),
+            ("café", Definition::parse("n. A coffee house. See Coffee.")),
+            ("감자", Definition::parse("A potato.")),
+            ("naïve", Definition::parse("1. Simple. 2. Artless.")),
+            ("zebra", Definition::parse("n. (Zool.) A striped horse.")),
+        ];
+        let mut writer = DictionaryWriter::create(&dir).unwrap();
+        for (word, definition) in &entries {
+            writer.write_entry(word, definition).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let store = DefinitionStore::open(&dir).unwrap();
+        assert_eq!(store.words().count(), entries.len());
+        for (word, definition) in [&entries[0], &entries[2], &entries[4]] {
+            assert_eq!(&store.definition(word).unwrap(), definition);
         }
+        assert!(store.definition("adieu").is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,291 @@
+use crate::language::Language;
+use crate::utils::storage::data_dir;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Result};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Day of the first daily puzzle (2024-01-01) in days since 1970-01-01
+pub const FIRST_DAY: u64 = 19723;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Helper function for the seconds since 1970-01-01 UTC
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Helper function for today's date in days since 1970-01-01.
+/// The date is taken in UTC, so every player gets the new puzzle at the same moment.
+pub fn today() -> u64 {
+    now() / SECONDS_PER_DAY
+}
+
+/// Helper function for the seconds left until the next daily puzzle
+pub fn seconds_until_next() -> u64 {
+    SECONDS_PER_DAY - now() % SECONDS_PER_DAY
+}
+
+/// Helper function for the number of the daily puzzle of a day, the first puzzle is #1
+pub fn puzzle_number(day: u64) -> u64 {
+    day.saturating_sub(FIRST_DAY) + 1
+}
+
+/// Helper function for the day of a daily puzzle number, None for a number no day has
+pub fn puzzle_day(number: u64) -> Option<u64> {
+    number
+        .checked_sub(1)
+        .and_then(|days| FIRST_DAY.checked_add(days))
+}
+
+/// Helper function that writes a day as a date, e.g. 2024-03-01 (days to civil date from Howard Hinnant's date algorithms)
+pub fn date_string(day: u64) -> String {
+    let z = day as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day_of_month)
+}
+
+/// Helper function that formats a number of seconds as a countdown, e.g. 05:42:17
+pub fn countdown(seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// The fixed order in which the words of a word list are the daily answer.
+/// The words are ordered by their SHA-256 hash, which looks random but only depends on the words themselves,
+/// so everyone with the same word list gets the same answer on the same date.
+/// Words that can't be the answer (blocked or excluded) keep their place in the order and are only skipped when the answer of a day is picked,
+/// so changing the blocklist doesn't move the answers of the other days.
+#[derive(Debug, Clone, Default)]
+pub struct DailySchedule {
+    words: Vec<String>,
+    skipped: HashSet<String>,
+}
+
+impl DailySchedule {
+    /// Constructor method that orders the words of the game's word length
+    pub fn new<'a>(words: impl Iterator<Item = &'a String>, language: &Language) -> Self {
+        let mut words: Vec<(Vec<u8>, String)> = words
+            .filter(|word| language.tiles(word).len() == language.word_length)
+            .map(|word| (Sha256::digest(word.as_bytes()).to_vec(), word.clone()))
+            .collect();
+        words.sort();
+
+        DailySchedule {
+            words: words.into_iter().map(|(_, word)| word).collect(),
+            skipped: HashSet::new(),
+        }
+    }
+
+    /// Method that skips every word of the schedule that isn't one of the answers
+    pub fn skip_unless_answer(&mut self, answers: &[String]) {
+        let answers: HashSet<&String> = answers.iter().collect();
+        self.skipped = self
+            .words
+            .iter()
+            .filter(|word| !answers.contains(word))
+            .cloned()
+            .collect();
+    }
+
+    /// Method for the answer of a day, the schedule starts over once every word has been the answer.
+    /// A day that lands on a skipped word gets the next word of the schedule.
+    pub fn word(&self, day: u64) -> Option<&String> {
+        if self.words.is_empty() {
+            return None;
+        }
+        let index = (day.saturating_sub(FIRST_DAY) % self.words.len() as u64) as usize;
+        self.words[index..]
+            .iter()
+            .chain(&self.words[..index])
+            .find(|word| !self.skipped.contains(*word))
+    }
+}
+
+/// The result of a daily puzzle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyResult {
+    pub word: String,
+    pub won: bool,
+    pub guesses: usize,
+}
+
+/// The daily puzzles a player has finished, by date.
+/// They are kept per language in the user data directory so every date can only be played once.
+/// - results: puzzles played on their own date, only these count for the streak
+/// - archive: past puzzles played later from the archive
+/// - in_progress: the guesses of puzzles that were started but not finished, so leaving a puzzle doesn't allow a fresh start
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DailyResults {
+    #[serde(default)]
+    pub results: BTreeMap<String, DailyResult>,
+    #[serde(default)]
+    pub archive: BTreeMap<String, DailyResult>,
+    #[serde(default)]
+    pub in_progress: BTreeMap<String, Vec<Vec<String>>>,
+}
+
+/// Helper function for the file that holds the daily results of a language
+fn daily_results_path(language: &Language) -> PathBuf {
+    data_dir().join(format!("daily_{}.json", language.code))
+}
+
+impl DailyResults {
+    /// Helper function that reads the daily results of a language, a missing file means none were played
+    pub fn load(language: &Language) -> Result<Self> {
+        let path = daily_results_path(language);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Method that writes the daily results of a language back to the user data directory
+    pub fn save(&self, language: &Language) -> Result<()> {
+        let writer = BufWriter::new(File::create(daily_results_path(language))?);
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Method for the result of a day, played on the day or from the archive, None if it wasn't played yet
+    pub fn get(&self, day: u64) -> Option<&DailyResult> {
+        let date = date_string(day);
+        self.results.get(&date).or_else(|| self.archive.get(&date))
+    }
+
+    /// Method for the guesses of a day that was started but not finished, as tiles
+    pub fn progress(&self, day: u64) -> Option<&Vec<Vec<String>>> {
+        self.in_progress.get(&date_string(day))
+    }
+
+    /// Method that saves the guesses of a day that is being played
+    pub fn save_progress(
+        &mut self,
+        language: &Language,
+        day: u64,
+        guesses: Vec<Vec<String>>,
+    ) -> Result<()> {
+        self.in_progress.insert(date_string(day), guesses);
+        self.save(language)
+    }
+
+    /// Method that records the result of a day and saves it, archive results are kept apart
+    pub fn record(
+        &mut self,
+        language: &Language,
+        day: u64,
+        archive: bool,
+        result: DailyResult,
+    ) -> Result<()> {
+        let results = if archive {
+            &mut self.archive
+        } else {
+            &mut self.results
+        };
+        results.insert(date_string(day), result);
+        self.in_progress.remove(&date_string(day));
+        self.save(language)
+    }
+
+    /// Helper function to check if a day was won on its own date
+    fn won_on_the_day(&self, day: u64) -> bool {
+        self.results
+            .get(&date_string(day))
+            .is_some_and(|result| result.won)
+    }
+
+    /// Method for the number of days in a row that were won on their own date.
+    /// Today's puzzle doesn't break the streak before it is played, archive results never count.
+    pub fn current_streak(&self, today: u64) -> usize {
+        let mut day = today;
+        if !self.results.contains_key(&date_string(today)) {
+            day = today.saturating_sub(1);
+        }
+
+        let mut streak = 0;
+        while day >= FIRST_DAY && self.won_on_the_day(day) {
+            streak += 1;
+            day -= 1;
+        }
+        streak
+    }
+
+    /// Method for the longest run of days that were won on their own date
+    pub fn best_streak(&self, today: u64) -> usize {
+        let mut best = 0;
+        let mut streak = 0;
+        for day in FIRST_DAY..=today {
+            if self.won_on_the_day(day) {
+                streak += 1;
+                best = best.max(streak);
+            } else {
+                streak = 0;
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::ENGLISH;
+
+    #[test]
+    fn puzzle_numbers_are_days() {
+        assert_eq!(puzzle_day(1), Some(FIRST_DAY));
+        assert_eq!(
+            puzzle_day(puzzle_number(FIRST_DAY + 41)),
+            Some(FIRST_DAY + 41)
+        );
+        assert_eq!(puzzle_day(0), None);
+        assert_eq!(puzzle_day(u64::MAX), None);
+    }
+
+    #[test]
+    fn skipped_words_keep_the_other_days() {
+        let words: Vec<String> = ["apple", "berry", "chili", "grape", "lemon", "mango"]
+            .iter()
+            .map(|word| word.to_string())
+            .collect();
+        let mut schedule = DailySchedule::new(words.iter(), &ENGLISH);
+        let before: Vec<String> = (0..5)
+            .map(|day| schedule.word(FIRST_DAY + day).unwrap().clone())
+            .collect();
+
+        let answers: Vec<String> = words
+            .iter()
+            .filter(|word| **word != before[1])
+            .cloned()
+            .collect();
+        schedule.skip_unless_answer(&answers);
+
+        assert_eq!(schedule.word(FIRST_DAY).unwrap(), &before[0]);
+        assert_eq!(schedule.word(FIRST_DAY + 1).unwrap(), &before[2]);
+        assert_eq!(schedule.word(FIRST_DAY + 3).unwrap(), &before[3]);
+    }
+}
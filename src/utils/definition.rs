@@ -0,0 +1,387 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Part of speech abbreviations Webster puts in front of a sense, longest first so "v. t." wins over "v."
+const PARTS_OF_SPEECH: &[(&str, &str)] = &[
+    ("v. t.", "transitive verb"),
+    ("v. i.", "intransitive verb"),
+    ("p. p.", "past participle"),
+    ("p. pr.", "present participle"),
+    ("interj.", "interjection"),
+    ("conj.", "conjunction"),
+    ("prep.", "preposition"),
+    ("pron.", "pronoun"),
+    ("adv.", "adverb"),
+    ("adj.", "adjective"),
+    ("imp.", "imperfect"),
+    ("pl.", "plural"),
+    ("n.", "noun"),
+    ("v.", "verb"),
+    ("a.", "adjective"),
+];
+
+/// Phrases that point to another entry
+const CROSS_REFERENCES: &[&str] = &["See under ", "See ", "Same as "];
+
+/// A single meaning of a word
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Sense {
+    /// number of the meaning in the source ("2." in Webster), None if the word has a single unnumbered meaning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_of_speech: Option<String>,
+    /// usage labels like "Obs.", "Zool." or "Colloq."
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    pub text: String,
+    /// usage notes ("Note: ...")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    /// entries the sense refers to ("See X", "Same as X")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub see_also: Vec<String>,
+}
+
+/// The definition of a word split into its senses.
+/// Cleaned dictionaries written before definitions were structured hold plain strings, these are parsed when they are read.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredDefinition")]
+pub struct Definition {
+    pub senses: Vec<Sense>,
+}
+
+/// The forms a definition can be stored in
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredDefinition {
+    Text(String),
+    Structured { senses: Vec<Sense> },
+}
+
+impl From<StoredDefinition> for Definition {
+    fn from(stored: StoredDefinition) -> Self {
+        match stored {
+            StoredDefinition::Text(text) => Definition::parse(&text),
+            StoredDefinition::Structured { senses } => Definition { senses },
+        }
+    }
+}
+
+/// Helper function that finds where the numbered senses start: "1. ", "2. ", ... in order, beginning at the given number.
+/// Only the next expected number counts, so a year like "1850. " in the text doesn't split a sense.
+fn sense_starts(text: &str, first: u32) -> Vec<(usize, usize, u32)> {
+    let mut starts = Vec::new();
+    let mut expected = first;
+    let mut marker = format!("{}. ", first);
+
+    for (i, _) in text.char_indices() {
+        let at_word_start = i == 0 || text[..i].ends_with(char::is_whitespace);
+        if at_word_start && text[i..].starts_with(&marker) {
+            starts.push((i, i + marker.len(), expected));
+            expected += 1;
+            marker = format!("{}. ", expected);
+        }
+    }
+
+    starts
+}
+
+/// Helper function that takes the labels in brackets out of a sense: leading "(Zool.)" and "[Obs.]" anywhere
+fn take_labels(text: &str, labels: &mut Vec<String>) -> String {
+    let mut rest = text.trim();
+
+    // leading labels in round or square brackets
+    while rest.starts_with('(') || rest.starts_with('[') {
+        let close = if rest.starts_with('(') { ')' } else { ']' };
+        match rest.find(close) {
+            Some(end) => {
+                labels.push(rest[1..end].trim().to_string());
+                rest = rest[end + 1..].trim_start();
+            }
+            None => break,
+        }
+    }
+
+    // square bracket labels anywhere else
+    let mut cleaned = String::with_capacity(rest.len());
+    let mut remaining = rest;
+    while let Some(start) = remaining.find('[') {
+        match remaining[start..].find(']') {
+            Some(end) => {
+                labels.push(remaining[start + 1..start + end].trim().to_string());
+                cleaned.push_str(&remaining[..start]);
+                remaining = &remaining[start + end + 1..];
+            }
+            None => break,
+        }
+    }
+    cleaned.push_str(remaining);
+
+    cleaned.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Helper function that takes the cross-references ("See X.", "Same as X.") out of a sense
+fn take_cross_references(text: &str, see_also: &mut Vec<String>) -> String {
+    let mut cleaned = String::with_capacity(text.len());
+    let mut remaining = text;
+
+    loop {
+        let found = CROSS_REFERENCES
+            .iter()
+            .filter_map(|phrase| remaining.find(phrase).map(|start| (start, *phrase)))
+            .min_by_key(|(start, phrase)| (*start, usize::MAX - phrase.len()));
+        let Some((start, phrase)) = found else {
+            break;
+        };
+
+        let target_start = start + phrase.len();
+        let target_end = remaining[target_start..]
+            .find(['.', ';', ','])
+            .map(|end| target_start + end)
+            .unwrap_or(remaining.len());
+        let target = remaining[target_start..target_end].trim();
+        if !target.is_empty() {
+            see_also.push(target.to_string());
+        }
+
+        cleaned.push_str(&remaining[..start]);
+        remaining = remaining[target_end..].trim_start_matches(['.', ';', ',']);
+    }
+    cleaned.push_str(remaining);
+
+    cleaned
+        .trim()
+        .trim_end_matches([';', ','])
+        .trim()
+        .to_string()
+}
+
+/// Helper function that reads the part of speech in front of a sense, verb senses written as "To ..." count as verbs
+fn take_part_of_speech(text: &str) -> (Option<String>, &str) {
+    for (abbreviation, name) in PARTS_OF_SPEECH {
+        if let Some(rest) = text.strip_prefix(abbreviation) {
+            if rest.is_empty() || rest.starts_with(' ') {
+                return (Some(name.to_string()), rest.trim_start());
+            }
+        }
+    }
+
+    if text.starts_with("To ") {
+        return (Some("verb".to_string()), text);
+    }
+
+    (None, text)
+}
+
+impl Sense {
+    /// Constructor method that parses the text of a single sense
+    fn parse(number: Option<u32>, text: &str) -> Self {
+        let mut sense = Sense {
+            number,
+            ..Default::default()
+        };
+
+        // usage notes follow "Note:" until the end of the sense
+        let (text, notes) = match text.find("Note:") {
+            Some(start) => (&text[..start], Some(&text[start + "Note:".len()..])),
+            None => (text, None),
+        };
+        if let Some(note) = notes.map(str::trim).filter(|note| !note.is_empty()) {
+            sense.notes.push(note.to_string());
+        }
+
+        let text = take_labels(text, &mut sense.labels);
+        let (part_of_speech, text) = take_part_of_speech(&text);
+        sense.part_of_speech = part_of_speech;
+        let text = take_labels(text, &mut sense.labels);
+        sense.text = take_cross_references(&text, &mut sense.see_also);
+
+        sense
+    }
+
+    /// Method for a short title of the sense, used as the header of its section
+    pub fn title(&self) -> String {
+        let mut title = match self.number {
+            Some(number) => format!("{}.", number),
+            None => String::new(),
+        };
+        if let Some(part_of_speech) = &self.part_of_speech {
+            title = format!("{} {}", title, part_of_speech);
+        }
+
+        // a sense that only points to another entry is titled with the reference
+        let text = match (self.text.is_empty(), self.see_also.first()) {
+            (true, Some(see)) => format!("See {}", see),
+            _ => self.text.clone(),
+        };
+        let preview: String = text.chars().take(40).collect();
+        let ellipsis = if text.chars().count() > 40 { "..." } else { "" };
+        format!("{} {}{}", title, preview, ellipsis)
+            .trim()
+            .to_string()
+    }
+}
+
+impl Definition {
+    /// Function that parses a Webster style definition into its senses.
+    /// Numbered meanings ("1. ... 2. ...") become separate senses, each with its part of speech,
+    /// its usage labels ("[Obs.]", "(Zool.)"), notes ("Note: ...") and cross-references ("See X").
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        if text.is_empty() {
+            return Definition::default();
+        }
+
+        // some entries leave the first number out and start counting at "2."
+        let mut starts = sense_starts(text, 1);
+        let mut leading_number = None;
+        if starts.is_empty() {
+            starts = sense_starts(text, 2);
+            if !starts.is_empty() {
+                leading_number = Some(1);
+            }
+        }
+        let mut senses = Vec::new();
+
+        // text in front of the first number is a sense of its own (or the only one if nothing is numbered)
+        let first = starts
+            .first()
+            .map(|(start, _, _)| *start)
+            .unwrap_or(text.len());
+        if !text[..first].trim().is_empty() {
+            senses.push(Sense::parse(leading_number, &text[..first]));
+        }
+
+        for (i, (_, text_start, number)) in starts.iter().enumerate() {
+            let end = starts
+                .get(i + 1)
+                .map(|(start, _, _)| *start)
+                .unwrap_or(text.len());
+            senses.push(Sense::parse(Some(*number), &text[*text_start..end]));
+        }
+
+        Definition { senses }
+    }
+
+    /// Function for a definition that comes as separate glosses with a known part of speech (Wiktionary)
+    pub fn from_glosses(part_of_speech: Option<&str>, glosses: &[&String]) -> Self {
+        let numbered = glosses.len() > 1;
+        let senses = glosses
+            .iter()
+            .enumerate()
+            .map(|(i, gloss)| {
+                let mut sense = Sense::parse(numbered.then_some(i as u32 + 1), gloss);
+                if let Some(part_of_speech) = part_of_speech {
+                    sense.part_of_speech = Some(part_of_speech.to_string());
+                }
+                sense
+            })
+            .collect();
+
+        Definition { senses }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.senses.is_empty()
+    }
+}
+
+/// The definition as plain text, one sense per line
+impl fmt::Display for Definition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for sense in &self.senses {
+            if let Some(number) = sense.number {
+                write!(f, "{}. ", number)?;
+            }
+            if let Some(part_of_speech) = &sense.part_of_speech {
+                write!(f, "({}) ", part_of_speech)?;
+            }
+            for label in &sense.labels {
+                write!(f, "[{}] ", label)?;
+            }
+            write!(f, "{}", sense.text)?;
+            for see in &sense.see_also {
+                write!(f, " See {}.", see)?;
+            }
+            for note in &sense.notes {
+                write!(f, " Note: {}", note)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbered_senses_are_parsed_one_by_one() {
+        let definition = Definition::parse(
+            "n. A small boat. 2. (Naut.) A sail of a ship. [Obs.] 3. v. t. To row. See Oar. Note: Rarely used.",
+        );
+
+        // the first number is left out, the text in front of "2." is the first sense
+        assert_eq!(definition.senses.len(), 3);
+        assert_eq!(
+            definition.senses[0],
+            Sense {
+                number: Some(1),
+                part_of_speech: Some("noun".to_string()),
+                text: "A small boat.".to_string(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            definition.senses[1],
+            Sense {
+                number: Some(2),
+                labels: vec!["Naut.".to_string(), "Obs.".to_string()],
+                text: "A sail of a ship.".to_string(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            definition.senses[2],
+            Sense {
+                number: Some(3),
+                part_of_speech: Some("transitive verb".to_string()),
+                text: "To row.".to_string(),
+                notes: vec!["Rarely used.".to_string()],
+                see_also: vec!["Oar".to_string()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn a_year_does_not_start_a_sense() {
+        let definition =
+            Definition::parse("1. A coin first struck in 1794. 2. A city founded 1850. Its port.");
+
+        let texts: Vec<&str> = definition
+            .senses
+            .iter()
+            .map(|sense| sense.text.as_str())
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                "A coin first struck in 1794.",
+                "A city founded 1850. Its port."
+            ]
+        );
+    }
+
+    #[test]
+    fn plain_text_definitions_are_read_as_senses() {
+        let stored: Definition = serde_json::from_str("\"1. First. 2. Second.\"").unwrap();
+        assert_eq!(stored, Definition::parse("1. First. 2. Second."));
+
+        let json = serde_json::to_string(&stored).unwrap();
+        assert_eq!(serde_json::from_str::<Definition>(&json).unwrap(), stored);
+    }
+}
@@ -1,22 +1,420 @@
 use crate::utils::errors::DictionaryError;
-use reqwest::blocking::get;
-use std::fs::File;
-use std::io::Write;
-use std::path::Path;
+use reqwest::blocking::Client;
+use reqwest::header::RANGE;
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-/// Download the raw dictionary from the given URL if it doesn't already exist.
+/// Longest wait between two attempts, however many retries there are
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Settings used when downloading the raw dictionary.
+/// - expected_sha256: optional hex encoded SHA-256 the downloaded file has to match
+/// - retries: how many times a failed download is retried before giving up
+/// - backoff: the wait before the first retry, doubled after every failed attempt up to a minute
+/// - timeout: the maximum time a single attempt is allowed to take
+#[derive(Debug, Clone)]
+pub struct DownloadOptions {
+    pub expected_sha256: Option<String>,
+    pub retries: u32,
+    pub backoff: Duration,
+    pub timeout: Duration,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            expected_sha256: None,
+            retries: 3,
+            backoff: Duration::from_secs(1),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Download the raw dictionary from the given URL to the destination.
+/// The body is streamed into a temporary file next to the destination, which is only renamed once the download is complete
+/// (and matches the expected checksum), so a failed download never leaves a corrupt file behind.
+/// A download that broke off is resumed from the temporary file, by the next attempt or the next run.
+/// The progress callback receives the number of bytes downloaded so far and the total size if the server reports it.
 /// This function either returns Ok when the dictionary is downloaded or a DictionaryError
-pub fn download_raw_dictionary(url: &str, destination: &Path) -> Result<(), DictionaryError> {
+pub fn download_raw_dictionary(
+    url: &str,
+    destination: &Path,
+    options: &DownloadOptions,
+    progress: &mut dyn FnMut(u64, Option<u64>),
+) -> Result<(), DictionaryError> {
     println!("Downloading raw dictionary from {}...", url);
-    let response = get(url)
-        .map_err(|_| DictionaryError::DownloadError)?
-        .text()
+    let client = Client::builder()
+        .timeout(options.timeout)
+        .build()
+        .map_err(|_| DictionaryError::DownloadError)?;
+
+    let temp_path = temporary_path(destination);
+    let mut attempt = 0;
+
+    loop {
+        let result = download_attempt(&client, url, &temp_path, options, progress);
+
+        match result {
+            Ok(_) => {
+                fs::rename(&temp_path, destination).map_err(|_| DictionaryError::PathError)?;
+                println!("Raw dictionary downloaded and saved to {:?}", destination);
+                return Ok(());
+            }
+            Err(e) => {
+                // the destination is not writable, so retrying won't help
+                if let DictionaryError::PathError = e {
+                    return Err(e);
+                }
+                // the downloaded bytes are wrong, the next attempt starts over
+                if let DictionaryError::ChecksumError = e {
+                    let _ = fs::remove_file(&temp_path);
+                }
+
+                if attempt >= options.retries {
+                    return Err(e);
+                }
+
+                let wait = backoff(options, attempt);
+                println!("{} (attempt {}), retrying in {:?}", e, attempt + 1, wait);
+                thread::sleep(wait);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Helper function for the wait after a failed attempt, doubled every time up to the longest wait
+fn backoff(options: &DownloadOptions, attempt: u32) -> Duration {
+    options
+        .backoff
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF)
+}
+
+/// Helper function that performs a single download attempt into the temporary file.
+/// If the temporary file holds the start of the body, only the rest is requested.
+/// A server that doesn't support ranges sends the whole body again, which then replaces the file.
+fn download_attempt(
+    client: &Client,
+    url: &str,
+    temp_path: &Path,
+    options: &DownloadOptions,
+    progress: &mut dyn FnMut(u64, Option<u64>),
+) -> Result<(), DictionaryError> {
+    let resume_from = fs::metadata(temp_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if resume_from > 0 {
+        request = request.header(RANGE, format!("bytes={}-", resume_from));
+    }
+    let response = request.send().map_err(|_| DictionaryError::DownloadError)?;
+    // the temporary file doesn't fit the body on the server, the next attempt starts over
+    if response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+        let _ = fs::remove_file(temp_path);
+        return Err(DictionaryError::DownloadError);
+    }
+    let mut response = response
+        .error_for_status()
         .map_err(|_| DictionaryError::DownloadError)?;
 
-    let mut file = File::create(destination).map_err(|_| DictionaryError::PathError)?;
-    file.write_all(response.as_bytes())
+    let mut hasher = Sha256::new();
+    let mut downloaded: u64 = 0;
+    let file = if response.status() == StatusCode::PARTIAL_CONTENT {
+        // the bytes that are already there are part of the checksum as well
+        let mut existing = File::open(temp_path).map_err(|_| DictionaryError::PathError)?;
+        std::io::copy(&mut existing, &mut hasher).map_err(|_| DictionaryError::PathError)?;
+        downloaded = resume_from;
+        OpenOptions::new().append(true).open(temp_path)
+    } else {
+        File::create(temp_path)
+    }
+    .map_err(|_| DictionaryError::PathError)?;
+    let total = response.content_length().map(|length| length + downloaded);
+
+    let mut writer = BufWriter::new(file);
+    let mut buffer = [0u8; 64 * 1024];
+
+    progress(downloaded, total);
+    loop {
+        let read = match response.read(&mut buffer) {
+            Ok(read) => read,
+            Err(_) => {
+                // keep what arrived so far, the next attempt resumes from there
+                writer.flush().map_err(|_| DictionaryError::PathError)?;
+                return Err(DictionaryError::DownloadError);
+            }
+        };
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buffer[..read]);
+        writer
+            .write_all(&buffer[..read])
+            .map_err(|_| DictionaryError::PathError)?;
+        downloaded += read as u64;
+        progress(downloaded, total);
+    }
+
+    writer.flush().map_err(|_| DictionaryError::PathError)?;
+
+    // a connection that was closed early can look like a finished body
+    if let Some(total) = total {
+        if downloaded != total {
+            return Err(DictionaryError::DownloadError);
+        }
+    }
+
+    let file = writer
+        .into_inner()
         .map_err(|_| DictionaryError::PathError)?;
+    file.sync_all().map_err(|_| DictionaryError::PathError)?;
+
+    if let Some(expected) = &options.expected_sha256 {
+        let actual: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        if !actual.eq_ignore_ascii_case(expected.trim()) {
+            return Err(DictionaryError::ChecksumError);
+        }
+    }
 
-    println!("Raw dictionary downloaded and saved to {:?}", destination);
     Ok(())
 }
+
+/// Helper function for the path of the partial download, e.g. raw_dictionary.json.part
+fn temporary_path(destination: &Path) -> PathBuf {
+    let mut file_name = destination
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    file_name.push(".part");
+    destination.with_file_name(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    const BODY: &[u8] = b"{\"apple\": \"A fruit.\", \"crane\": \"A bird.\"}";
+
+    /// A local stand-in for the dictionary server: every connection gets the next of the scripted responses.
+    /// Returns the URL and the request lines and headers the server received.
+    fn serve(responses: Vec<Vec<u8>>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/dictionary.json", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    request.push_str(&line.to_lowercase());
+                }
+                received.lock().unwrap().push(request);
+
+                let _ = stream.write_all(&response);
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn response(status: &str, headers: &str, body: &[u8]) -> Vec<u8> {
+        let mut response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n{}\r\n",
+            status,
+            body.len(),
+            headers
+        )
+        .into_bytes();
+        response.extend_from_slice(body);
+        response
+    }
+
+    fn sha256(bytes: &[u8]) -> String {
+        Sha256::digest(bytes)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn destination(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("rustywordle-download-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let destination = dir.join(name);
+        let _ = fs::remove_file(&destination);
+        let _ = fs::remove_file(temporary_path(&destination));
+        destination
+    }
+
+    fn options(expected_sha256: Option<String>, retries: u32) -> DownloadOptions {
+        DownloadOptions {
+            expected_sha256,
+            retries,
+            backoff: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn downloads_and_renames_the_temporary_file() {
+        let (url, _) = serve(vec![response("200 OK", "", BODY)]);
+        let destination = destination("plain.json");
+        let mut reported = Vec::new();
+
+        download_raw_dictionary(
+            &url,
+            &destination,
+            &options(Some(sha256(BODY)), 0),
+            &mut |downloaded, total| reported.push((downloaded, total)),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read(&destination).unwrap(), BODY);
+        assert!(!temporary_path(&destination).exists());
+        assert_eq!(
+            reported.last(),
+            Some(&(BODY.len() as u64, Some(BODY.len() as u64)))
+        );
+    }
+
+    #[test]
+    fn retries_after_a_server_error() {
+        let (url, requests) = serve(vec![
+            response("500 Internal Server Error", "", b""),
+            response("200 OK", "", BODY),
+        ]);
+        let destination = destination("retry.json");
+
+        download_raw_dictionary(&url, &destination, &options(None, 1), &mut |_, _| {}).unwrap();
+
+        assert_eq!(fs::read(&destination).unwrap(), BODY);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn gives_up_after_the_retries() {
+        let (url, requests) = serve(vec![
+            response("503 Service Unavailable", "", b""),
+            response("503 Service Unavailable", "", b""),
+        ]);
+        let destination = destination("unavailable.json");
+
+        let result = download_raw_dictionary(&url, &destination, &options(None, 1), &mut |_, _| {});
+
+        assert!(matches!(result, Err(DictionaryError::DownloadError)));
+        assert!(!destination.exists());
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn rejects_a_checksum_mismatch() {
+        let (url, _) = serve(vec![response("200 OK", "", BODY)]);
+        let destination = destination("mismatch.json");
+
+        let result = download_raw_dictionary(
+            &url,
+            &destination,
+            &options(Some(sha256(b"another file")), 0),
+            &mut |_, _| {},
+        );
+
+        assert!(matches!(result, Err(DictionaryError::ChecksumError)));
+        assert!(!destination.exists());
+        assert!(!temporary_path(&destination).exists());
+    }
+
+    #[test]
+    fn resumes_a_broken_download_from_the_temporary_file() {
+        let split = 10;
+        // the first response promises the whole body but the connection closes after a part of it
+        let mut broken = response("200 OK", "", BODY);
+        broken.truncate(broken.len() - (BODY.len() - split));
+        let range = format!(
+            "Content-Range: bytes {}-{}/{}\r\n",
+            split,
+            BODY.len() - 1,
+            BODY.len()
+        );
+        let (url, requests) = serve(vec![
+            broken,
+            response("206 Partial Content", &range, &BODY[split..]),
+        ]);
+        let destination = destination("resume.json");
+
+        download_raw_dictionary(
+            &url,
+            &destination,
+            &options(Some(sha256(BODY)), 1),
+            &mut |_, _| {},
+        )
+        .unwrap();
+
+        assert_eq!(fs::read(&destination).unwrap(), BODY);
+        assert!(!temporary_path(&destination).exists());
+        let requests = requests.lock().unwrap();
+        assert!(!requests[0].contains("range:"));
+        assert!(requests[1].contains(&format!("range: bytes={}-", split)));
+    }
+
+    #[test]
+    fn resumes_from_a_previous_run() {
+        let split = 5;
+        let destination = destination("previous.json");
+        fs::write(temporary_path(&destination), &BODY[..split]).unwrap();
+        let range = format!(
+            "Content-Range: bytes {}-{}/{}\r\n",
+            split,
+            BODY.len() - 1,
+            BODY.len()
+        );
+        let (url, _) = serve(vec![response(
+            "206 Partial Content",
+            &range,
+            &BODY[split..],
+        )]);
+
+        download_raw_dictionary(
+            &url,
+            &destination,
+            &options(Some(sha256(BODY)), 0),
+            &mut |_, _| {},
+        )
+        .unwrap();
+
+        assert_eq!(fs::read(&destination).unwrap(), BODY);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_longest_wait() {
+        let options = DownloadOptions {
+            backoff: Duration::from_secs(1),
+            retries: 100,
+            ..Default::default()
+        };
+
+        assert_eq!(backoff(&options, 0), Duration::from_secs(1));
+        assert_eq!(backoff(&options, 3), Duration::from_secs(8));
+        assert_eq!(backoff(&options, 40), MAX_BACKOFF);
+        assert_eq!(backoff(&options, options.retries), MAX_BACKOFF);
+    }
+}
@@ -1,10 +1,12 @@
 use std::fmt;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum DictionaryError {
     PathError,
     ParseError,
     DownloadError,
+    ChecksumError,
 }
 
 impl fmt::Display for DictionaryError {
@@ -13,6 +15,39 @@ impl fmt::Display for DictionaryError {
             DictionaryError::PathError => write!(f, "Dictionary Error: Path Error"),
             DictionaryError::ParseError => write!(f, "Dictionary Error: Parse Error"),
             DictionaryError::DownloadError => write!(f, "Dictionary Error: Download Error"),
+            DictionaryError::ChecksumError => write!(f, "Dictionary Error: Checksum Mismatch"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PuzzleCodeError {
+    InvalidCode,
+    ChecksumError,
+}
+
+impl fmt::Display for PuzzleCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleCodeError::InvalidCode => write!(f, "Puzzle Code Error: Not a puzzle code"),
+            PuzzleCodeError::ChecksumError => {
+                write!(f, "Puzzle Code Error: The code has a typo")
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ShareError {
+    NoRows,
+    UnevenRows,
+}
+
+impl fmt::Display for ShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShareError::NoRows => write!(f, "Share Error: No rows of colored squares found"),
+            ShareError::UnevenRows => write!(f, "Share Error: The rows have different lengths"),
         }
     }
 }
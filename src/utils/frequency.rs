@@ -0,0 +1,146 @@
+use crate::states::Difficulty;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Result};
+use std::path::Path;
+
+/// Share of the most common words that can be picked on Easy, and of the rarest words that are picked on Hard
+const COMMON_SHARE: f64 = 0.4;
+
+/// Letters that rarely show up in words and make a word awkward to guess
+const RARE_LETTERS: &[char] = &['j', 'k', 'q', 'v', 'x', 'z'];
+
+/// File with the player's own word frequencies next to the cleaned dictionary
+pub const FREQUENCY_FILE: &str = "word_frequency.txt";
+
+/// File with the word frequencies of the source word list, written by the cleaner for CSV/TSV lists with a frequency column
+pub const SOURCE_FREQUENCY_FILE: &str = "source_frequency.txt";
+
+/// Function that reads the word frequencies of a dictionary directory: the ones of the source word list
+/// and the player's own file on top, a word the player lists keeps the player's frequency
+pub fn load_word_frequencies(dictionary_dir: &Path) -> Result<HashMap<String, f64>> {
+    let mut frequencies = load_frequencies(&dictionary_dir.join(SOURCE_FREQUENCY_FILE))?;
+    frequencies.extend(load_frequencies(&dictionary_dir.join(FREQUENCY_FILE))?);
+    Ok(frequencies)
+}
+
+/// Helper function that reads word frequencies from a local file.
+/// Every line holds a word and how often it is used, separated by whitespace, a comma or a tab.
+/// A missing file is not an error, the game then builds the difficulty pools from the letters alone.
+pub fn load_frequencies(path: &Path) -> Result<HashMap<String, f64>> {
+    let mut frequencies = HashMap::new();
+    if !path.exists() {
+        return Ok(frequencies);
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    for line in reader.lines() {
+        let line = line?;
+        let mut fields = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|field| !field.is_empty());
+
+        if let (Some(word), Some(frequency)) = (fields.next(), fields.next()) {
+            if let Ok(frequency) = frequency.parse::<f64>() {
+                frequencies.insert(word.to_lowercase(), frequency);
+            }
+        }
+    }
+
+    Ok(frequencies)
+}
+
+/// Helper function to check if a word uses a letter more than once
+fn has_repeated_letters(word: &str) -> bool {
+    let mut letters: Vec<char> = word.chars().collect();
+    letters.sort_unstable();
+    letters.windows(2).any(|pair| pair[0] == pair[1])
+}
+
+/// Helper function for awkward letter patterns: rare letters or three consonants in a row
+fn is_awkward(word: &str) -> bool {
+    let mut consonant_run = 0;
+    for c in word.chars() {
+        if RARE_LETTERS.contains(&c) {
+            return true;
+        }
+        if "aeiouy".contains(c) {
+            consonant_run = 0;
+        } else {
+            consonant_run += 1;
+            if consonant_run >= 3 {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Function that narrows the candidate words down to the pool for the given difficulty.
+/// Words are ranked by their frequency (words without frequency data count as rare) and by how common their letters are.
+/// - Easy: the most common words, without repeated letters and with letters that are at least as common as average
+/// - Normal: all candidates
+/// - Hard: the rarest words and any word with repeated letters or an awkward pattern
+///
+/// If the pool for a difficulty would be empty all candidates are returned, so a small word list still works.
+pub fn difficulty_pool<'a>(
+    candidates: &[&'a String],
+    frequencies: &HashMap<String, f64>,
+    difficulty: Difficulty,
+) -> Vec<&'a String> {
+    if difficulty == Difficulty::Normal || candidates.is_empty() {
+        return candidates.to_vec();
+    }
+
+    // how often each letter appears among the candidates
+    let mut letter_counts: HashMap<char, usize> = HashMap::new();
+    for word in candidates {
+        for c in word.chars() {
+            *letter_counts.entry(c).or_insert(0) += 1;
+        }
+    }
+    let letter_score = |word: &str| {
+        word.chars().map(|c| letter_counts[&c] as f64).sum::<f64>() / word.chars().count() as f64
+    };
+    let mut letter_scores: Vec<f64> = candidates.iter().map(|word| letter_score(word)).collect();
+    letter_scores.sort_by(|a, b| a.total_cmp(b));
+    let median_letter_score = letter_scores[letter_scores.len() / 2];
+
+    // rank the words from most to least common
+    let mut ranked = candidates.to_vec();
+    ranked.sort_by(|a, b| {
+        let frequency_a = frequencies.get(a.as_str()).copied().unwrap_or(0.0);
+        let frequency_b = frequencies.get(b.as_str()).copied().unwrap_or(0.0);
+        frequency_b.total_cmp(&frequency_a)
+    });
+    let has_frequencies = candidates
+        .iter()
+        .any(|word| frequencies.contains_key(word.as_str()));
+    let cutoff = (ranked.len() as f64 * COMMON_SHARE).ceil() as usize;
+
+    let pool: Vec<&String> = ranked
+        .iter()
+        .enumerate()
+        .filter(|(rank, word)| match difficulty {
+            Difficulty::Easy => {
+                (!has_frequencies || *rank < cutoff)
+                    && !has_repeated_letters(word)
+                    && letter_score(word) >= median_letter_score
+            }
+            Difficulty::Hard => {
+                (has_frequencies && *rank >= ranked.len() - cutoff)
+                    || has_repeated_letters(word)
+                    || is_awkward(word)
+            }
+            Difficulty::Normal => true,
+        })
+        .map(|(_, word)| *word)
+        .collect();
+
+    if pool.is_empty() {
+        candidates.to_vec()
+    } else {
+        pool
+    }
+}
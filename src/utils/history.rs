@@ -0,0 +1,43 @@
+use crate::states::Difficulty;
+use crate::utils::storage::data_dir;
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{Result, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A finished game as it is kept in the game history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub word: String,
+    pub difficulty: Difficulty,
+    pub won: bool,
+    pub guesses: usize,
+    pub timestamp: u64,
+}
+
+impl GameRecord {
+    /// Constructor method for a record of a game that just finished
+    pub fn new(word: &str, difficulty: Difficulty, won: bool, guesses: usize) -> Self {
+        Self {
+            word: word.to_string(),
+            difficulty,
+            won,
+            guesses,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Helper function that appends a finished game to the history file (one JSON record per line)
+pub fn record_game(record: &GameRecord) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(data_dir().join("history.jsonl"))?;
+
+    let line = serde_json::to_string(record)?;
+    writeln!(file, "{}", line)
+}
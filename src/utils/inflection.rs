@@ -0,0 +1,365 @@
+/// Irregular noun plurals that the regular rules would get wrong
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("calf", "calves"),
+    ("child", "children"),
+    ("die", "dice"),
+    ("elf", "elves"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("half", "halves"),
+    ("knife", "knives"),
+    ("leaf", "leaves"),
+    ("life", "lives"),
+    ("loaf", "loaves"),
+    ("louse", "lice"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("scarf", "scarves"),
+    ("shelf", "shelves"),
+    ("thief", "thieves"),
+    ("tooth", "teeth"),
+    ("wife", "wives"),
+    ("wolf", "wolves"),
+    ("woman", "women"),
+];
+
+/// Irregular verbs as (lemma, past tense, past participle)
+const IRREGULAR_VERBS: &[(&str, &str, &str)] = &[
+    ("arise", "arose", "arisen"),
+    ("awake", "awoke", "awoken"),
+    ("be", "was", "been"),
+    ("bear", "bore", "borne"),
+    ("beat", "beat", "beaten"),
+    ("become", "became", "become"),
+    ("begin", "began", "begun"),
+    ("bend", "bent", "bent"),
+    ("bind", "bound", "bound"),
+    ("bite", "bit", "bitten"),
+    ("bleed", "bled", "bled"),
+    ("blow", "blew", "blown"),
+    ("break", "broke", "broken"),
+    ("breed", "bred", "bred"),
+    ("bring", "brought", "brought"),
+    ("build", "built", "built"),
+    ("burn", "burnt", "burnt"),
+    ("buy", "bought", "bought"),
+    ("catch", "caught", "caught"),
+    ("choose", "chose", "chosen"),
+    ("cling", "clung", "clung"),
+    ("come", "came", "come"),
+    ("creep", "crept", "crept"),
+    ("deal", "dealt", "dealt"),
+    ("dig", "dug", "dug"),
+    ("do", "did", "done"),
+    ("draw", "drew", "drawn"),
+    ("dream", "dreamt", "dreamt"),
+    ("drink", "drank", "drunk"),
+    ("drive", "drove", "driven"),
+    ("dwell", "dwelt", "dwelt"),
+    ("eat", "ate", "eaten"),
+    ("fall", "fell", "fallen"),
+    ("feed", "fed", "fed"),
+    ("feel", "felt", "felt"),
+    ("fight", "fought", "fought"),
+    ("find", "found", "found"),
+    ("flee", "fled", "fled"),
+    ("fling", "flung", "flung"),
+    ("fly", "flew", "flown"),
+    ("forget", "forgot", "forgotten"),
+    ("freeze", "froze", "frozen"),
+    ("get", "got", "gotten"),
+    ("give", "gave", "given"),
+    ("go", "went", "gone"),
+    ("grind", "ground", "ground"),
+    ("grow", "grew", "grown"),
+    ("hang", "hung", "hung"),
+    ("have", "had", "had"),
+    ("hear", "heard", "heard"),
+    ("hide", "hid", "hidden"),
+    ("hold", "held", "held"),
+    ("keep", "kept", "kept"),
+    ("kneel", "knelt", "knelt"),
+    ("know", "knew", "known"),
+    ("lay", "laid", "laid"),
+    ("lead", "led", "led"),
+    ("lean", "leant", "leant"),
+    ("leap", "leapt", "leapt"),
+    ("leave", "left", "left"),
+    ("lend", "lent", "lent"),
+    ("lie", "lay", "lain"),
+    ("light", "lit", "lit"),
+    ("lose", "lost", "lost"),
+    ("make", "made", "made"),
+    ("mean", "meant", "meant"),
+    ("meet", "met", "met"),
+    ("pay", "paid", "paid"),
+    ("ride", "rode", "ridden"),
+    ("ring", "rang", "rung"),
+    ("rise", "rose", "risen"),
+    ("run", "ran", "run"),
+    ("say", "said", "said"),
+    ("see", "saw", "seen"),
+    ("seek", "sought", "sought"),
+    ("sell", "sold", "sold"),
+    ("send", "sent", "sent"),
+    ("shake", "shook", "shaken"),
+    ("shine", "shone", "shone"),
+    ("shoot", "shot", "shot"),
+    ("sing", "sang", "sung"),
+    ("sink", "sank", "sunk"),
+    ("sit", "sat", "sat"),
+    ("slay", "slew", "slain"),
+    ("sleep", "slept", "slept"),
+    ("slide", "slid", "slid"),
+    ("sling", "slung", "slung"),
+    ("smite", "smote", "smitten"),
+    ("speak", "spoke", "spoken"),
+    ("speed", "sped", "sped"),
+    ("spend", "spent", "spent"),
+    ("spin", "spun", "spun"),
+    ("spit", "spat", "spat"),
+    ("spring", "sprang", "sprung"),
+    ("stand", "stood", "stood"),
+    ("steal", "stole", "stolen"),
+    ("stick", "stuck", "stuck"),
+    ("sting", "stung", "stung"),
+    ("stink", "stank", "stunk"),
+    ("stride", "strode", "stridden"),
+    ("strike", "struck", "struck"),
+    ("strive", "strove", "striven"),
+    ("swear", "swore", "sworn"),
+    ("sweep", "swept", "swept"),
+    ("swim", "swam", "swum"),
+    ("swing", "swung", "swung"),
+    ("take", "took", "taken"),
+    ("teach", "taught", "taught"),
+    ("tear", "tore", "torn"),
+    ("tell", "told", "told"),
+    ("think", "thought", "thought"),
+    ("throw", "threw", "thrown"),
+    ("tread", "trod", "trodden"),
+    ("wake", "woke", "woken"),
+    ("wear", "wore", "worn"),
+    ("weave", "wove", "woven"),
+    ("weep", "wept", "wept"),
+    ("win", "won", "won"),
+    ("wind", "wound", "wound"),
+    ("wring", "wrung", "wrung"),
+    ("write", "wrote", "written"),
+];
+
+/// Words ending in -o that take -es (hero -> heroes, go -> goes), every other word ending in -o only takes -s (solo -> solos)
+const O_ES_WORDS: &[&str] = &[
+    "do", "echo", "embargo", "forgo", "go", "hero", "outdo", "potato", "redo", "tomato", "torpedo",
+    "undergo", "undo", "veto",
+];
+
+/// Verbs whose third person singular does not follow the -s rules
+const IRREGULAR_THIRD_PERSON: &[(&str, &str)] = &[("be", "is"), ("have", "has")];
+
+/// Parts of speech that get inflected forms
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WordClass {
+    Noun,
+    Verb,
+}
+
+/// Helper function to check if a letter is a vowel
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Helper function that drops the sense number dot and leading labels like "(Zool.)" or "[Obs.]" from a sense
+pub fn strip_labels(sense: &str) -> &str {
+    let mut sense = sense.trim_start_matches(['.', ' ']);
+    while sense.starts_with('(') || sense.starts_with('[') {
+        match sense.find([')', ']']) {
+            Some(end) => sense = sense[end + 1..].trim_start(),
+            None => break,
+        }
+    }
+    sense
+}
+
+/// Helper function to guess the word classes of a lemma from its Webster definition.
+/// Verb senses are written as "To ...", noun senses start with an article or "One who ...".
+/// Every numbered sense is checked, so a word that is both a noun and a verb gets both kinds of forms.
+fn word_classes(definition: &str) -> Vec<WordClass> {
+    let mut classes = Vec::new();
+
+    // senses are separated by numbers ("1. ...  2. ...") or semicolons
+    for sense in definition.split(|c: char| c == ';' || c.is_ascii_digit()) {
+        let sense = strip_labels(sense);
+
+        let class = if sense.starts_with("To ") {
+            Some(WordClass::Verb)
+        } else if ["A ", "An ", "The ", "One ", "Any ", "That which"]
+            .iter()
+            .any(|prefix| sense.starts_with(prefix))
+        {
+            Some(WordClass::Noun)
+        } else {
+            None
+        };
+
+        if let Some(class) = class {
+            if !classes.contains(&class) {
+                classes.push(class);
+            }
+        }
+    }
+
+    classes
+}
+
+/// Helper function for the -s form used by noun plurals and the third person singular of verbs
+fn add_s(word: &str) -> String {
+    let mut chars = word.chars().rev();
+    let last = chars.next();
+    let before_last = chars.next();
+
+    match (before_last, last) {
+        (_, Some('s' | 'x' | 'z')) | (Some('c' | 's'), Some('h')) => format!("{}es", word),
+        (Some(c), Some('y')) if !is_vowel(c) => format!("{}ies", &word[..word.len() - 1]),
+        (_, Some('o')) if O_ES_WORDS.contains(&word) => format!("{}es", word),
+        _ => format!("{}s", word),
+    }
+}
+
+/// Helper function that checks if the final consonant is doubled before -ed and -ing.
+/// This is the case for short words that end in a single vowel followed by a single consonant (stop -> stopped).
+fn doubles_final_consonant(word: &str) -> bool {
+    let chars: Vec<char> = word.chars().collect();
+    if chars.len() < 3 {
+        return false;
+    }
+
+    let (a, b, c) = (
+        chars[chars.len() - 3],
+        chars[chars.len() - 2],
+        chars[chars.len() - 1],
+    );
+    let vowel_groups = chars
+        .iter()
+        .enumerate()
+        .filter(|(i, &ch)| is_vowel(ch) && (*i == 0 || !is_vowel(chars[i - 1])))
+        .count();
+
+    vowel_groups == 1 && !is_vowel(a) && is_vowel(b) && !is_vowel(c) && !"wxy".contains(c)
+}
+
+/// Helper function for the regular past tense
+fn add_ed(word: &str) -> String {
+    let last = word.chars().last();
+    let before_last = word.chars().rev().nth(1);
+
+    match (before_last, last) {
+        (_, Some('e')) => format!("{}d", word),
+        (Some(c), Some('y')) if !is_vowel(c) => format!("{}ied", &word[..word.len() - 1]),
+        _ if doubles_final_consonant(word) => {
+            format!("{}{}ed", word, word.chars().last().unwrap())
+        }
+        _ => format!("{}ed", word),
+    }
+}
+
+/// Helper function for the present participle
+fn add_ing(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ie") {
+        return format!("{}ying", stem);
+    }
+
+    if word.len() > 2 && word.ends_with('e') && !word.ends_with("ee") && !word.ends_with("ye") {
+        return format!("{}ing", &word[..word.len() - 1]);
+    }
+
+    if doubles_final_consonant(word) {
+        return format!("{}{}ing", word, word.chars().last().unwrap());
+    }
+
+    format!("{}ing", word)
+}
+
+/// Function that generates the inflected forms of a lemma.
+/// The word class is taken from the definition: nouns get their plural and verbs get their -s, past tense,
+/// past participle and -ing forms. Irregular forms (goose -> geese, write -> wrote) come from a fixed table.
+/// Lemmas without a recognisable definition are not inflected, so word lists that already contain their forms are left alone.
+pub fn inflected_forms(lemma: &str, definition: &str) -> Vec<String> {
+    let mut forms = Vec::new();
+    if lemma.is_empty() || !lemma.chars().all(|c| c.is_ascii_lowercase()) {
+        return forms;
+    }
+
+    for class in word_classes(definition) {
+        match class {
+            WordClass::Noun => match IRREGULAR_PLURALS.iter().find(|(l, _)| *l == lemma) {
+                Some((_, plural)) => forms.push(plural.to_string()),
+                None => forms.push(add_s(lemma)),
+            },
+            WordClass::Verb => {
+                match IRREGULAR_THIRD_PERSON.iter().find(|(l, _)| *l == lemma) {
+                    Some((_, form)) => forms.push(form.to_string()),
+                    None => forms.push(add_s(lemma)),
+                }
+                forms.push(add_ing(lemma));
+                match IRREGULAR_VERBS.iter().find(|(l, _, _)| *l == lemma) {
+                    Some((_, past, participle)) => {
+                        forms.push(past.to_string());
+                        forms.push(participle.to_string());
+                    }
+                    None => forms.push(add_ed(lemma)),
+                }
+            }
+        }
+    }
+
+    forms.sort();
+    forms.dedup();
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_ending_in_o_take_s() {
+        for (word, plural) in [
+            ("solo", "solos"),
+            ("silo", "silos"),
+            ("memo", "memos"),
+            ("typo", "typos"),
+            ("logo", "logos"),
+            ("demo", "demos"),
+            ("radio", "radios"),
+        ] {
+            assert_eq!(add_s(word), plural);
+        }
+    }
+
+    #[test]
+    fn listed_words_ending_in_o_take_es() {
+        for (word, plural) in [
+            ("hero", "heroes"),
+            ("potato", "potatoes"),
+            ("tomato", "tomatoes"),
+            ("echo", "echoes"),
+            ("veto", "vetoes"),
+            ("go", "goes"),
+            ("do", "does"),
+        ] {
+            assert_eq!(add_s(word), plural);
+        }
+    }
+
+    #[test]
+    fn other_plural_rules_are_kept() {
+        assert_eq!(add_s("box"), "boxes");
+        assert_eq!(add_s("church"), "churches");
+        assert_eq!(add_s("city"), "cities");
+        assert_eq!(add_s("day"), "days");
+        assert_eq!(add_s("crane"), "cranes");
+    }
+}
@@ -0,0 +1,335 @@
+use crate::utils::definition::Definition;
+use crate::utils::errors::DictionaryError;
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::fmt;
+use std::io::BufRead;
+use std::path::Path;
+
+/// A single word read from a dictionary source.
+/// Sources that only list words leave the definition empty and not every source knows how common a word is.
+/// The definition is kept as the source wrote it, which is used to tag and inflect the word, and parsed into its senses for display.
+#[derive(Debug, Clone)]
+pub struct DictionaryEntry {
+    pub word: String,
+    pub definition: String,
+    pub structured: Definition,
+    pub frequency: Option<f64>,
+}
+
+/// Trait for the different dictionary source formats.
+/// A loader reads the source and hands every entry it finds to the callback.
+pub trait DictionaryLoader {
+    fn load(
+        &self,
+        reader: &mut dyn BufRead,
+        on_entry: &mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    ) -> Result<(), DictionaryError>;
+}
+
+/// Loader for the Webster JSON shape: `{"word": "definition", ...}`.
+/// The object is read one entry at a time, so the source is never held in memory as a whole.
+pub struct WebsterJsonLoader;
+
+/// Visitor that hands the entries of the Webster object to the callback as they are parsed
+struct WebsterVisitor<'a> {
+    on_entry: &'a mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    /// error of the callback, serde can only pass its own errors up
+    error: &'a mut Option<DictionaryError>,
+}
+
+impl<'de> Visitor<'de> for WebsterVisitor<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an object of words and their definitions")
+    }
+
+    fn visit_map<M: MapAccess<'de>>(self, mut map: M) -> Result<(), M::Error> {
+        while let Some((word, definition)) = map.next_entry::<String, String>()? {
+            let entry = DictionaryEntry {
+                word: word.trim().to_lowercase(),
+                structured: Definition::parse(&definition),
+                definition,
+                frequency: None,
+            };
+            if let Err(e) = (self.on_entry)(entry) {
+                *self.error = Some(e);
+                return Err(de::Error::custom("entry could not be handled"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DictionaryLoader for WebsterJsonLoader {
+    fn load(
+        &self,
+        reader: &mut dyn BufRead,
+        on_entry: &mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    ) -> Result<(), DictionaryError> {
+        let mut error = None;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let result = deserializer.deserialize_map(WebsterVisitor {
+            on_entry,
+            error: &mut error,
+        });
+
+        match (error, result) {
+            (Some(e), _) => Err(e),
+            (None, Err(_)) => Err(DictionaryError::ParseError),
+            (None, Ok(())) => deserializer.end().map_err(|_| DictionaryError::ParseError),
+        }
+    }
+}
+
+/// A line of a Wiktionary JSON lines dump (as extracted by wiktextract), only the fields the game uses
+#[derive(Deserialize)]
+struct WiktionaryLine {
+    word: String,
+    pos: Option<String>,
+    #[serde(default)]
+    senses: Vec<WiktionarySense>,
+}
+
+#[derive(Deserialize)]
+struct WiktionarySense {
+    #[serde(default)]
+    glosses: Vec<String>,
+}
+
+/// Loader for Wiktionary dumps in JSON lines form: one `{"word": ..., "senses": [{"glosses": [...]}]}` object per line.
+/// The glosses are numbered like Webster senses ("1. ... 2. ..."), so they are tagged and inflected the same way,
+/// and become the senses of the definition together with the part of speech of the line.
+pub struct JsonLinesLoader;
+
+impl DictionaryLoader for JsonLinesLoader {
+    fn load(
+        &self,
+        reader: &mut dyn BufRead,
+        on_entry: &mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    ) -> Result<(), DictionaryError> {
+        for line in reader.lines() {
+            let line = line.map_err(|_| DictionaryError::ParseError)?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let parsed: WiktionaryLine =
+                serde_json::from_str(&line).map_err(|_| DictionaryError::ParseError)?;
+            let glosses: Vec<&String> = parsed
+                .senses
+                .iter()
+                .flat_map(|sense| sense.glosses.iter())
+                .collect();
+            let definition = match glosses.as_slice() {
+                [gloss] => gloss.to_string(),
+                glosses => glosses
+                    .iter()
+                    .enumerate()
+                    .map(|(i, gloss)| format!("{}. {}", i + 1, gloss))
+                    .collect::<Vec<String>>()
+                    .join(" "),
+            };
+
+            on_entry(DictionaryEntry {
+                word: parsed.word.trim().to_lowercase(),
+                structured: Definition::from_glosses(parsed.pos.as_deref(), &glosses),
+                definition,
+                frequency: None,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Loader for plain text word lists with one word per line.
+/// Empty lines and lines starting with '#' are skipped.
+pub struct PlainTextLoader;
+
+impl DictionaryLoader for PlainTextLoader {
+    fn load(
+        &self,
+        reader: &mut dyn BufRead,
+        on_entry: &mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    ) -> Result<(), DictionaryError> {
+        for line in reader.lines() {
+            let line = line.map_err(|_| DictionaryError::ParseError)?;
+            let word = line.trim();
+            if word.is_empty() || word.starts_with('#') {
+                continue;
+            }
+
+            on_entry(DictionaryEntry {
+                word: word.to_lowercase(),
+                definition: String::new(),
+                structured: Definition::default(),
+                frequency: None,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Loader for CSV and TSV word lists.
+/// The first column is the word, optionally followed by a frequency and/or a definition column.
+/// If the first row is a header naming the columns ("word", "frequency", "definition") the columns can be in any order,
+/// otherwise a numeric second column is taken as the frequency and the next column as the definition.
+pub struct DelimitedLoader {
+    pub delimiter: char,
+}
+
+/// Column positions of a delimited source
+struct Columns {
+    word: usize,
+    frequency: Option<usize>,
+    definition: Option<usize>,
+}
+
+impl DelimitedLoader {
+    /// Helper function to split a line into fields, honouring double quoted fields that contain the delimiter
+    fn split_fields(&self, line: &str) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = !in_quotes,
+                c if c == self.delimiter && !in_quotes => {
+                    fields.push(field.trim().to_string());
+                    field.clear();
+                }
+                c => field.push(c),
+            }
+        }
+        fields.push(field.trim().to_string());
+
+        fields
+    }
+
+    /// Helper function to read the column positions from a header row, returns None if the row is not a header
+    fn header_columns(fields: &[String]) -> Option<Columns> {
+        let position = |names: &[&str]| {
+            fields
+                .iter()
+                .position(|field| names.contains(&field.to_lowercase().as_str()))
+        };
+
+        Some(Columns {
+            word: position(&["word", "words"])?,
+            frequency: position(&["frequency", "freq", "count"]),
+            definition: position(&["definition", "definitions", "meaning"]),
+        })
+    }
+
+    /// Helper function to guess the column positions from the first data row
+    fn positional_columns(fields: &[String]) -> Columns {
+        let numeric_second = fields
+            .get(1)
+            .is_some_and(|field| field.parse::<f64>().is_ok());
+
+        match (fields.len(), numeric_second) {
+            (1, _) => Columns {
+                word: 0,
+                frequency: None,
+                definition: None,
+            },
+            (_, true) => Columns {
+                word: 0,
+                frequency: Some(1),
+                definition: if fields.len() > 2 { Some(2) } else { None },
+            },
+            (_, false) => Columns {
+                word: 0,
+                frequency: None,
+                definition: Some(1),
+            },
+        }
+    }
+}
+
+impl DictionaryLoader for DelimitedLoader {
+    fn load(
+        &self,
+        reader: &mut dyn BufRead,
+        on_entry: &mut dyn FnMut(DictionaryEntry) -> Result<(), DictionaryError>,
+    ) -> Result<(), DictionaryError> {
+        let mut columns: Option<Columns> = None;
+
+        for line in reader.lines() {
+            let line = line.map_err(|_| DictionaryError::ParseError)?;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = self.split_fields(&line);
+
+            // the first row decides the layout and is skipped if it is a header
+            let layout = match columns {
+                Some(ref layout) => layout,
+                None => {
+                    if let Some(header) = Self::header_columns(&fields) {
+                        columns = Some(header);
+                        continue;
+                    }
+                    columns.insert(Self::positional_columns(&fields))
+                }
+            };
+
+            let word = match fields.get(layout.word) {
+                Some(word) if !word.is_empty() => word.to_lowercase(),
+                _ => continue,
+            };
+            let frequency = match layout.frequency.and_then(|i| fields.get(i)) {
+                Some(value) if !value.is_empty() => {
+                    Some(value.parse().map_err(|_| DictionaryError::ParseError)?)
+                }
+                _ => None,
+            };
+            let definition = layout
+                .definition
+                .and_then(|i| fields.get(i))
+                .cloned()
+                .unwrap_or_default();
+
+            on_entry(DictionaryEntry {
+                word,
+                structured: Definition::parse(&definition),
+                definition,
+                frequency,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Helper function that picks the loader matching the file extension of a dictionary source.
+/// - .json: Webster JSON
+/// - .jsonl: Wiktionary JSON lines
+/// - .csv: comma separated
+/// - .tsv: tab separated
+/// - anything else: plain text with one word per line
+pub fn loader_for_path(path: &Path) -> Box<dyn DictionaryLoader> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase());
+
+    match extension.as_deref() {
+        Some("json") => Box::new(WebsterJsonLoader),
+        Some("jsonl") => Box::new(JsonLinesLoader),
+        Some("csv") => Box::new(DelimitedLoader { delimiter: ',' }),
+        Some("tsv") => Box::new(DelimitedLoader { delimiter: '\t' }),
+        _ => Box::new(PlainTextLoader),
+    }
+}
@@ -0,0 +1,155 @@
+use crate::game_logic::{DEFAULT_ATTEMPTS, MAX_ATTEMPTS};
+use crate::language::Language;
+use crate::utils::storage::data_dir;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Result};
+use std::path::PathBuf;
+
+/// Number of runs kept in the best-run table
+pub const TABLE_SIZE: usize = 10;
+
+/// Attempts a solved word adds to the pool of a marathon
+pub const WORD_ALLOWANCE: usize = 4;
+
+/// Struct for a marathon: words are played one after the other from one pool of attempts.
+/// The pool starts with the default number of attempts, every guess is taken from it and every solved word adds a fixed allowance,
+/// up to the most a game can have. The run is over when the pool is empty.
+#[derive(Debug, Clone)]
+pub struct MarathonRun {
+    /// attempts left in the pool, the word that is played now can use all of them, zero once the run is over
+    pub attempts: usize,
+    /// the solved words with the guesses they took
+    pub solved: Vec<(String, usize)>,
+    /// the word that ended the run
+    pub missed: Option<String>,
+}
+
+impl Default for MarathonRun {
+    fn default() -> Self {
+        Self {
+            attempts: DEFAULT_ATTEMPTS,
+            solved: Vec::new(),
+            missed: None,
+        }
+    }
+}
+
+impl MarathonRun {
+    /// Method for a solved word, its guesses are taken from the pool and the allowance is added
+    pub fn solve(&mut self, word: &str, guesses: usize) {
+        let left = self.attempts.saturating_sub(guesses);
+        self.attempts = (left + WORD_ALLOWANCE).min(MAX_ATTEMPTS);
+        self.solved.push((word.to_string(), guesses));
+    }
+
+    /// Method for a word that wasn't solved: it used every attempt of the pool, so the run is over
+    pub fn fail(&mut self, word: &str) {
+        self.attempts = 0;
+        self.missed = Some(word.to_string());
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.attempts == 0
+    }
+
+    /// Method for the score of the run, the number of solved words
+    pub fn score(&self) -> usize {
+        self.solved.len()
+    }
+}
+
+/// A finished marathon as it is kept in the best-run table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarathonRecord {
+    pub solved: usize,
+    pub word_length: usize,
+    pub date: String,
+}
+
+/// The best marathons of a language, best first, kept in the user data directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarathonTable {
+    #[serde(default)]
+    pub runs: Vec<MarathonRecord>,
+}
+
+/// Helper function for the file that holds the best-run table of a language
+fn marathon_table_path(language: &Language) -> PathBuf {
+    data_dir().join(format!("marathon_{}.json", language.code))
+}
+
+impl MarathonTable {
+    /// Helper function that reads the best-run table of a language, a missing file means there are no runs yet
+    pub fn load(language: &Language) -> Result<Self> {
+        let path = marathon_table_path(language);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Method that writes the best-run table of a language back to the user data directory
+    pub fn save(&self, language: &Language) -> Result<()> {
+        let writer = BufWriter::new(File::create(marathon_table_path(language))?);
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Method that adds a finished run to the table if it is one of the best.
+    /// Returns the place of the run in the table (1 is the best), None if it didn't make it.
+    /// A new run goes behind earlier runs with the same score.
+    pub fn record(&mut self, language: &Language, record: MarathonRecord) -> Result<Option<usize>> {
+        if record.solved == 0 {
+            return Ok(None);
+        }
+
+        let place = self
+            .runs
+            .iter()
+            .position(|run| run.solved < record.solved)
+            .unwrap_or(self.runs.len());
+        if place >= TABLE_SIZE {
+            return Ok(None);
+        }
+
+        self.runs.insert(place, record);
+        self.runs.truncate(TABLE_SIZE);
+        self.save(language)?;
+        Ok(Some(place + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unused_attempts_carry_over_in_one_pool() {
+        let mut run = MarathonRun::default();
+        assert_eq!(run.attempts, DEFAULT_ATTEMPTS);
+
+        // 6 - 5 + 4
+        run.solve("crane", 5);
+        assert_eq!(run.attempts, 5);
+        // 5 - 5 + 4, a word solved on its last attempt leaves only the allowance
+        run.solve("slate", 5);
+        assert_eq!(run.attempts, WORD_ALLOWANCE);
+        assert!(!run.is_over());
+
+        run.fail("pious");
+        assert_eq!(run.attempts, 0);
+        assert!(run.is_over());
+        assert_eq!(run.score(), 2);
+    }
+
+    #[test]
+    fn the_pool_is_capped() {
+        let mut run = MarathonRun::default();
+        for _ in 0..5 {
+            run.solve("crane", 1);
+        }
+        assert_eq!(run.attempts, MAX_ATTEMPTS);
+    }
+}
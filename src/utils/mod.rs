@@ -1,4 +1,20 @@
+pub mod blocklist;
+pub mod classifier;
 pub mod cleaner;
+pub mod daily;
+pub mod definition;
 pub mod download;
 pub mod errors;
+pub mod frequency;
+pub mod history;
+pub mod inflection;
+pub mod loader;
+pub mod marathon;
+pub mod personal;
+pub mod puzzle;
 pub mod selector;
+pub mod share;
+pub mod stats;
+pub mod storage;
+pub mod store;
+pub mod timed;
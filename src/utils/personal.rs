@@ -0,0 +1,72 @@
+use crate::language::Language;
+use crate::utils::storage::data_dir;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Result};
+use std::path::PathBuf;
+
+/// The player's own word lists for a language, kept in the user data directory.
+/// - allowed: words that are accepted as guesses even though the dictionary doesn't know them
+/// - denied: words that are never picked as the answer
+///
+/// Words are stored as they are written, so they still match when the accent mode of the language changes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersonalWords {
+    #[serde(default)]
+    pub allowed: BTreeSet<String>,
+    #[serde(default)]
+    pub denied: BTreeSet<String>,
+}
+
+/// Helper function for the file that holds the personal words of a language
+fn personal_words_path(language: &Language) -> PathBuf {
+    data_dir().join(format!("my_words_{}.json", language.code))
+}
+
+impl PersonalWords {
+    /// Helper function that reads the personal words of a language, a missing file means both lists are empty
+    pub fn load(language: &Language) -> Result<Self> {
+        let path = personal_words_path(language);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Method that writes the personal words of a language back to the user data directory
+    pub fn save(&self, language: &Language) -> Result<()> {
+        let writer = BufWriter::new(File::create(personal_words_path(language))?);
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Method that merges the lists into the loaded word lists:
+    /// allowed words are added to the accepted guesses and denied words are removed from the answers
+    pub fn apply(
+        &self,
+        language: &Language,
+        answers: &mut Vec<String>,
+        accepted_guesses: &mut HashSet<String>,
+    ) {
+        accepted_guesses.extend(self.allowed.iter().map(|word| language.key(word)));
+
+        let denied: HashSet<String> = self.denied.iter().map(|word| language.key(word)).collect();
+        answers.retain(|word| !denied.contains(&language.key(word)));
+    }
+}
+
+/// Helper function that adds a word to the allowed words of a language
+pub fn allow_word(language: &Language, word: &str) -> Result<()> {
+    let mut personal = PersonalWords::load(language)?;
+    personal.allowed.insert(word.to_lowercase());
+    personal.save(language)
+}
+
+/// Helper function that adds a word to the denied words of a language
+pub fn deny_word(language: &Language, word: &str) -> Result<()> {
+    let mut personal = PersonalWords::load(language)?;
+    personal.denied.insert(word.to_lowercase());
+    personal.save(language)
+}
@@ -0,0 +1,167 @@
+use crate::utils::errors::PuzzleCodeError;
+use sha2::{Digest, Sha256};
+
+/// Letters of the puzzle codes (Crockford's base 32), without I, L, O and U so a code can be read out and typed back
+const CODE_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Key the puzzle is scrambled with, this only hides the word from a quick look at the code
+const OBFUSCATION_KEY: &[u8] = b"RustyWordle puzzle";
+
+/// Shortest word a puzzle can be made of
+pub const MIN_WORD_LENGTH: usize = 3;
+
+/// Number of checksum bytes at the end of a code, two let only about 1 in 65536 mistyped codes through
+const CHECKSUM_LENGTH: usize = 2;
+
+/// A puzzle made by a player to share with friends: the answer together with the settings it is played with
+#[derive(Debug, Clone, PartialEq)]
+pub struct PuzzleCode {
+    pub language: String,
+    pub word_length: usize,
+    pub attempts: usize,
+    pub word: String,
+}
+
+/// Helper function for the bytes the puzzle is checked with, so a mistyped code is noticed
+fn checksum(bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let hash = Sha256::digest(bytes);
+    [hash[0], hash[1]]
+}
+
+/// Helper function that scrambles (or unscrambles) the bytes of a puzzle with the repeated hash of the key
+fn scramble(bytes: &mut [u8]) {
+    let key = Sha256::digest(OBFUSCATION_KEY);
+    for (byte, k) in bytes.iter_mut().zip(key.iter().cycle()) {
+        *byte ^= k;
+    }
+}
+
+/// Helper function that writes bytes with the code alphabet, 5 bits per letter
+fn to_base32(bytes: &[u8]) -> String {
+    let mut code = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            code.push(CODE_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        code.push(CODE_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+
+    code
+}
+
+/// Helper function that reads a code back into bytes.
+/// Lower case letters, dashes and spaces are allowed, O is read as 0 and I and L as 1.
+fn from_base32(code: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in code.chars().filter(|c| !c.is_whitespace() && *c != '-') {
+        let c = match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        let value = CODE_ALPHABET
+            .iter()
+            .position(|&letter| letter as char == c)?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+impl PuzzleCode {
+    /// Method that turns the puzzle into a short code.
+    /// The settings and the word are written as "language:length:attempts:word", followed by two checksum bytes,
+    /// scrambled so the word can't be read from the code and written with letters and digits only.
+    pub fn encode(&self) -> String {
+        let mut bytes = format!(
+            "{}:{}:{}:{}",
+            self.language, self.word_length, self.attempts, self.word
+        )
+        .into_bytes();
+        bytes.extend(checksum(&bytes));
+        scramble(&mut bytes);
+
+        to_base32(&bytes)
+    }
+
+    /// Function that reads a puzzle from its code
+    pub fn decode(code: &str) -> Result<Self, PuzzleCodeError> {
+        let mut bytes = from_base32(code).ok_or(PuzzleCodeError::InvalidCode)?;
+        scramble(&mut bytes);
+
+        if bytes.len() < CHECKSUM_LENGTH {
+            return Err(PuzzleCodeError::InvalidCode);
+        }
+        let check = bytes.split_off(bytes.len() - CHECKSUM_LENGTH);
+        if checksum(&bytes)[..] != check[..] {
+            return Err(PuzzleCodeError::ChecksumError);
+        }
+
+        let text = String::from_utf8(bytes).map_err(|_| PuzzleCodeError::InvalidCode)?;
+        let mut fields = text.splitn(4, ':');
+        match (fields.next(), fields.next(), fields.next(), fields.next()) {
+            (Some(language), Some(word_length), Some(attempts), Some(word)) => Ok(PuzzleCode {
+                language: language.to_string(),
+                word_length: word_length
+                    .parse()
+                    .map_err(|_| PuzzleCodeError::InvalidCode)?,
+                attempts: attempts.parse().map_err(|_| PuzzleCodeError::InvalidCode)?,
+                word: word.to_string(),
+            }),
+            _ => Err(PuzzleCodeError::InvalidCode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_read_back() {
+        let puzzle = PuzzleCode {
+            language: "en".to_string(),
+            word_length: 5,
+            attempts: 4,
+            word: "crane".to_string(),
+        };
+        let code = puzzle.encode();
+        assert_eq!(PuzzleCode::decode(&code).unwrap(), puzzle);
+        assert_eq!(
+            PuzzleCode::decode(&code.to_lowercase().replace('0', "o")).unwrap(),
+            puzzle
+        );
+    }
+
+    #[test]
+    fn mistyped_codes_are_noticed() {
+        let code = PuzzleCode {
+            language: "en".to_string(),
+            word_length: 4,
+            attempts: 6,
+            word: "word".to_string(),
+        }
+        .encode();
+        for (i, c) in code.char_indices() {
+            let typo = if c == 'X' { 'Y' } else { 'X' };
+            let mistyped = format!("{}{}{}", &code[..i], typo, &code[i + 1..]);
+            assert!(PuzzleCode::decode(&mistyped).is_err(), "{}", mistyped);
+        }
+    }
+}
@@ -1,62 +1,125 @@
+use crate::language::Language;
+use crate::states::Difficulty;
+use crate::utils::frequency::difficulty_pool;
+use crate::utils::store::{DefinitionStore, INDEX_FILE};
 use rand::seq::SliceRandom;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufReader, Error, ErrorKind, Result};
 
-/// Helper function that reads the cleaned dictionary and returns the Hashmap to it
-pub fn json_to_hashmap() -> Result<HashMap<String, String>> {
-    // open the cleaned dictionary file
-    let file = match File::open("src/utils/clean_dictionary.json") {
+/// Helper function that reads the words of the cleaned dictionary of the language.
+/// Only the words and the offsets of their definitions are loaded, the definitions stay on disk until they are needed.
+pub fn load_dictionary(language: &Language) -> Result<DefinitionStore> {
+    match DefinitionStore::open(&language.dictionary_dir()) {
+        Ok(store) => Ok(store),
+        Err(e) => {
+            println!("Error reading {}: {}", INDEX_FILE, e);
+            Err(e)
+        }
+    }
+}
+
+/// Helper function that reads the accepted guesses written by the cleaner and returns them as a set
+pub fn accepted_guesses(language: &Language) -> Result<HashSet<String>> {
+    let file = match File::open(language.dictionary_dir().join("accepted_guesses.json")) {
         Ok(f) => f,
         Err(e) => {
-            println!("Error opening clean_dictionary.json: {}", e);
+            println!("Error opening accepted_guesses.json: {}", e);
             return Err(e);
         }
     };
 
-    // create a reader for the file and deserialize JSON into hashmap
     let reader = BufReader::new(file);
-    let dictionary: HashMap<String, String> = match serde_json::from_reader(reader) {
-        Ok(dict) => dict,
+    let accepted: HashSet<String> = match serde_json::from_reader(reader) {
+        Ok(words) => words,
         Err(e) => {
-            println!("Error reading or parsing clean_dictionary.json: {}", e);
+            println!("Error reading or parsing accepted_guesses.json: {}", e);
             return Err(e.into());
         }
     };
 
-    Ok(dictionary)
+    Ok(accepted)
 }
 
-/// Helper funtion to choose a random word and definition from the cleaned dictionary hashmap.
-/// This function returns a Result(word, definiton).
-pub fn pick_random_word(
-    dictionary: &HashMap<String, String>,
-    word_length: usize,
-) -> Result<(String, String)> {
-    // convert the dictionary keys (words) into a vector and pick a random word
-    let words: Vec<&String> = dictionary
-        .keys()
-        .filter(|&word| word.len() == word_length)
-        .collect();
-    let word = match words.choose(&mut rand::thread_rng()) {
-        Some(&w) => w,
-        None => {
-            let err_msg = "The dictionary is empty.";
-            eprintln!("{}", err_msg);
-            return Err(Error::new(ErrorKind::NotFound, err_msg));
+/// Struct for the words that can be the answer, with the pool of every difficulty.
+/// The pools are built once when the game is set up, so picking a word only chooses an index.
+/// The length of the words is counted in tiles of the language.
+/// Definitions are not part of the pool, they are fetched from the dictionary once the game is over.
+#[derive(Debug, Clone)]
+pub struct AnswerPool {
+    easy: Vec<String>,
+    /// every answer, sorted
+    normal: Vec<String>,
+    hard: Vec<String>,
+}
+
+impl AnswerPool {
+    /// Constructor method that keeps the answers of the word length and splits them by difficulty,
+    /// using the word frequencies if there are any
+    pub fn new<'a>(
+        answers: impl Iterator<Item = &'a String>,
+        language: &Language,
+        word_length: usize,
+        frequencies: &HashMap<String, f64>,
+    ) -> Self {
+        let mut candidates: Vec<&String> = answers
+            .filter(|word| language.tiles(word).len() == word_length)
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+
+        let pool = |difficulty| {
+            difficulty_pool(&candidates, frequencies, difficulty)
+                .into_iter()
+                .cloned()
+                .collect()
+        };
+        AnswerPool {
+            easy: pool(Difficulty::Easy),
+            hard: pool(Difficulty::Hard),
+            normal: candidates.into_iter().cloned().collect(),
         }
-    };
+    }
+
+    /// Method for all answers, sorted
+    pub fn words(&self) -> &[String] {
+        &self.normal
+    }
 
-    // get definition for the chosen word
-    let definition = match dictionary.get(word) {
-        Some(def) => def.clone(),
-        None => {
-            let err_msg = "The selected word does not have a definition.";
-            eprintln!("{}", err_msg);
-            return Err(Error::new(ErrorKind::NotFound, err_msg));
+    /// Method that takes a word out of the answers, its definition stays in the dictionary
+    pub fn remove(&mut self, word: &str) {
+        for pool in [&mut self.easy, &mut self.normal, &mut self.hard] {
+            pool.retain(|answer| answer != word);
         }
-    };
+    }
+
+    /// Helper function for the words of a difficulty
+    fn pool(&self, difficulty: Difficulty) -> &[String] {
+        match difficulty {
+            Difficulty::Easy => &self.easy,
+            Difficulty::Normal => &self.normal,
+            Difficulty::Hard => &self.hard,
+        }
+    }
+
+    /// Method to choose a random word from the pool of the chosen difficulty
+    pub fn pick(&self, difficulty: Difficulty) -> Result<String> {
+        match self.pool(difficulty).choose(&mut rand::thread_rng()) {
+            Some(word) => Ok(word.clone()),
+            None => {
+                let err_msg = "The dictionary is empty.";
+                eprintln!("{}", err_msg);
+                Err(Error::new(ErrorKind::NotFound, err_msg))
+            }
+        }
+    }
 
-    // return (word, definiton) tuple
-    Ok((word.clone(), definition))
+    /// Method to choose several different random words from the pool of the chosen difficulty,
+    /// the pool can have fewer words than asked for
+    pub fn pick_several(&self, difficulty: Difficulty, count: usize) -> Vec<String> {
+        self.pool(difficulty)
+            .choose_multiple(&mut rand::thread_rng(), count)
+            .cloned()
+            .collect()
+    }
 }
@@ -0,0 +1,111 @@
+use crate::states::LetterState;
+use crate::utils::errors::ShareError;
+
+/// Name at the start of every share string
+pub const SHARE_TITLE: &str = "RustyWordle";
+
+/// Helper function for the colored square of a letter, letters that are not in the word are black
+fn square(state: LetterState) -> char {
+    match state {
+        LetterState::Correct => '🟩',
+        LetterState::Present => '🟨',
+        LetterState::Wrong | LetterState::Unknown => '⬛',
+    }
+}
+
+/// Helper function that reads a colored square, the light theme and high contrast squares of other games are understood as well
+fn letter_state(square: char) -> Option<LetterState> {
+    match square {
+        '🟩' | '🟧' => Some(LetterState::Correct),
+        '🟨' | '🟦' => Some(LetterState::Present),
+        '⬛' | '⬜' => Some(LetterState::Wrong),
+        _ => None,
+    }
+}
+
+/// Function that writes the colors of a guess as squares, e.g. 🟩⬛🟨⬛⬛
+pub fn pattern_squares(pattern: &[LetterState]) -> String {
+    pattern.iter().map(|&state| square(state)).collect()
+}
+
+/// Function for the result of a game as it is shared with friends: a title with the guesses used and a row of squares per guess.
+/// The title names the daily puzzle, so a friend can play its grid in reverse mode against the same answer.
+///
+/// **Example**:
+/// RustyWordle Daily #42 3/6
+///
+/// ⬛🟨⬛⬛⬛
+/// 🟩⬛🟨🟨⬛
+/// 🟩🟩🟩🟩🟩
+pub fn share_string(
+    daily_number: Option<u64>,
+    guesses: &[Vec<LetterState>],
+    won: bool,
+    max_attempts: usize,
+) -> String {
+    let mut title = SHARE_TITLE.to_string();
+    if let Some(number) = daily_number {
+        title.push_str(&format!(" Daily #{}", number));
+    }
+    let score = if won {
+        guesses.len().to_string()
+    } else {
+        "X".to_string()
+    };
+
+    let rows: Vec<String> = guesses.iter().map(|row| pattern_squares(row)).collect();
+    format!(
+        "{} {}/{}\n\n{}",
+        title,
+        score,
+        max_attempts,
+        rows.join("\n")
+    )
+}
+
+/// The grid of a share string
+#[derive(Debug, Clone)]
+pub struct SharedGrid {
+    /// number of the daily puzzle the grid was played on, None for any other game
+    pub daily_number: Option<u64>,
+    pub rows: Vec<Vec<LetterState>>,
+}
+
+/// Function that reads the grid out of a share string.
+/// Every line made of colored squares is a row, the other lines are only read for the number of a daily puzzle.
+pub fn parse_share_string(text: &str) -> Result<SharedGrid, ShareError> {
+    let mut daily_number = None;
+    let mut rows: Vec<Vec<LetterState>> = Vec::new();
+
+    for line in text.lines() {
+        let squares: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+        if squares.is_empty() {
+            continue;
+        }
+
+        match squares
+            .iter()
+            .map(|&c| letter_state(c))
+            .collect::<Option<Vec<LetterState>>>()
+        {
+            Some(row) => rows.push(row),
+            None => {
+                if let Some((_, number)) = line.split_once("Daily #") {
+                    daily_number = number
+                        .split_whitespace()
+                        .next()
+                        .and_then(|number| number.parse().ok());
+                }
+            }
+        }
+    }
+
+    if rows.is_empty() {
+        return Err(ShareError::NoRows);
+    }
+    if rows.iter().any(|row| row.len() != rows[0].len()) {
+        return Err(ShareError::UnevenRows);
+    }
+
+    Ok(SharedGrid { daily_number, rows })
+}
@@ -0,0 +1,241 @@
+use crate::language::Language;
+use crate::utils::errors::DictionaryError;
+use crate::utils::loader::loader_for_path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Number of entries shown in the top lists of the report
+const TOP_ENTRIES: usize = 10;
+
+/// Statistics about a word list, used to judge a list before playing with it.
+/// Letter, bigram and elimination statistics only look at words of the game's word length.
+pub struct DictionaryStats {
+    pub language_name: &'static str,
+    pub word_length: usize,
+    pub total_words: usize,
+    /// number of words for every word length (in tiles)
+    pub words_per_length: BTreeMap<usize, usize>,
+    /// how often every letter is used, most common first
+    pub letter_frequency: Vec<(String, usize)>,
+    /// how often every letter is used at each position, most common first
+    pub position_frequency: Vec<Vec<(String, usize)>>,
+    /// number of words of the word length
+    pub playable_words: usize,
+    pub repeated_letter_words: usize,
+    /// adjacent letter pairs, most common first
+    pub bigrams: Vec<(String, usize)>,
+    /// words with the most other words one letter away, these can't be told apart by elimination (MATCH, BATCH, CATCH, ...)
+    pub hardest_words: Vec<(String, Vec<String>)>,
+}
+
+/// Helper function that counts strings and sorts them from most to least common, ties alphabetically
+fn ranked(counts: HashMap<String, usize>) -> Vec<(String, usize)> {
+    let mut ranked: Vec<(String, usize)> = counts.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+}
+
+impl DictionaryStats {
+    /// Constructor method that computes the statistics of a list of words in the given language
+    pub fn new(words: &[String], language: &Language) -> Self {
+        let word_length = language.word_length;
+        let mut words_per_length = BTreeMap::new();
+        let mut playable: Vec<Vec<String>> = Vec::new();
+
+        for word in words {
+            let tiles: Vec<String> = language.tiles(word);
+            *words_per_length.entry(tiles.len()).or_insert(0) += 1;
+            if tiles.len() == word_length {
+                playable.push(tiles);
+            }
+        }
+        playable.sort();
+        playable.dedup();
+
+        let mut letters: HashMap<String, usize> = HashMap::new();
+        let mut positions: Vec<HashMap<String, usize>> = vec![HashMap::new(); word_length];
+        let mut bigrams: HashMap<String, usize> = HashMap::new();
+        let mut repeated_letter_words = 0;
+
+        for tiles in &playable {
+            for (position, tile) in tiles.iter().enumerate() {
+                *letters.entry(tile.clone()).or_insert(0) += 1;
+                *positions[position].entry(tile.clone()).or_insert(0) += 1;
+            }
+            for pair in tiles.windows(2) {
+                *bigrams.entry(pair.concat()).or_insert(0) += 1;
+            }
+
+            let distinct: HashSet<&String> = tiles.iter().collect();
+            if distinct.len() < tiles.len() {
+                repeated_letter_words += 1;
+            }
+        }
+
+        DictionaryStats {
+            language_name: language.name,
+            word_length,
+            total_words: words.len(),
+            words_per_length,
+            letter_frequency: ranked(letters),
+            position_frequency: positions.into_iter().map(ranked).collect(),
+            playable_words: playable.len(),
+            repeated_letter_words,
+            bigrams: ranked(bigrams),
+            hardest_words: hardest_words(&playable, language),
+        }
+    }
+}
+
+/// Helper function that finds the words with the most neighbours: words that differ from them in a single letter.
+/// Once all other letters are known, each neighbour costs another guess, so these words are the hardest to solve by elimination.
+fn hardest_words(words: &[Vec<String>], language: &Language) -> Vec<(String, Vec<String>)> {
+    // group the words by every pattern with one letter left out ("_ATCH", "M_TCH", ...)
+    let mut groups: HashMap<(usize, Vec<&String>), Vec<usize>> = HashMap::new();
+    for (index, tiles) in words.iter().enumerate() {
+        for blank in 0..tiles.len() {
+            let pattern: Vec<&String> = tiles
+                .iter()
+                .enumerate()
+                .filter(|(position, _)| *position != blank)
+                .map(|(_, tile)| tile)
+                .collect();
+            groups.entry((blank, pattern)).or_default().push(index);
+        }
+    }
+
+    let mut neighbours: Vec<Vec<usize>> = vec![Vec::new(); words.len()];
+    for group in groups.values().filter(|group| group.len() > 1) {
+        for &word in group {
+            neighbours[word].extend(group.iter().filter(|&&other| other != word));
+        }
+    }
+
+    let mut ranked: Vec<(usize, Vec<usize>)> = neighbours.into_iter().enumerate().collect();
+    ranked.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+
+    ranked
+        .into_iter()
+        .filter(|(_, neighbours)| !neighbours.is_empty())
+        .take(TOP_ENTRIES)
+        .map(|(word, neighbours)| {
+            let mut neighbours: Vec<String> = neighbours
+                .into_iter()
+                .map(|other| language.display(&words[other]))
+                .collect();
+            neighbours.sort();
+            (language.display(&words[word]), neighbours)
+        })
+        .collect()
+}
+
+/// Helper function to format a count as a share of a total
+fn percent(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 * 100.0 / total as f64
+    }
+}
+
+impl fmt::Display for DictionaryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Dictionary statistics for {} ({} words)",
+            self.language_name, self.total_words
+        )?;
+
+        writeln!(f, "\nWords per length:")?;
+        for (length, count) in &self.words_per_length {
+            writeln!(f, "  {:>2}: {}", length, count)?;
+        }
+
+        writeln!(
+            f,
+            "\n{}-letter words: {}",
+            self.word_length, self.playable_words
+        )?;
+        if self.playable_words == 0 {
+            return Ok(());
+        }
+
+        let letter_total: usize = self.letter_frequency.iter().map(|(_, count)| count).sum();
+        writeln!(f, "\nLetter frequency:")?;
+        for row in self.letter_frequency.chunks(TOP_ENTRIES) {
+            let row: Vec<String> = row
+                .iter()
+                .map(|(letter, count)| format!("{} {:.1}%", letter, percent(*count, letter_total)))
+                .collect();
+            writeln!(f, "  {}", row.join("  "))?;
+        }
+
+        writeln!(f, "\nMost common letters per position:")?;
+        for (position, letters) in self.position_frequency.iter().enumerate() {
+            let row: Vec<String> = letters
+                .iter()
+                .take(TOP_ENTRIES)
+                .map(|(letter, count)| {
+                    format!("{} {:.1}%", letter, percent(*count, self.playable_words))
+                })
+                .collect();
+            writeln!(f, "  {:>2}: {}", position + 1, row.join("  "))?;
+        }
+
+        writeln!(
+            f,
+            "\nWords with repeated letters: {} ({:.1}%)",
+            self.repeated_letter_words,
+            percent(self.repeated_letter_words, self.playable_words)
+        )?;
+
+        let bigram_total: usize = self.bigrams.iter().map(|(_, count)| count).sum();
+        let bigrams: Vec<String> = self
+            .bigrams
+            .iter()
+            .take(TOP_ENTRIES)
+            .map(|(bigram, count)| format!("{} {:.1}%", bigram, percent(*count, bigram_total)))
+            .collect();
+        writeln!(f, "\nMost common bigrams:\n  {}", bigrams.join("  "))?;
+
+        writeln!(f, "\nHardest words by elimination (words one letter away):")?;
+        for (word, neighbours) in &self.hardest_words {
+            writeln!(
+                f,
+                "  {} ({}): {}",
+                word,
+                neighbours.len(),
+                neighbours.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Function that reads the words of a source file that isn't cleaned yet, so a new word list can be judged before it is used.
+/// Words with letters outside the language's alphabet are skipped.
+pub fn source_words(source: &Path, language: &Language) -> Result<Vec<String>, DictionaryError> {
+    let file = match File::open(source) {
+        Ok(f) => f,
+        Err(_) => {
+            return Err(DictionaryError::PathError);
+        }
+    };
+
+    let mut words = Vec::new();
+    let mut reader = BufReader::new(file);
+    loader_for_path(source).load(&mut reader, &mut |entry| {
+        if language.is_playable(&entry.word, usize::MAX) {
+            words.push(entry.word);
+        }
+        Ok(())
+    })?;
+    words.sort();
+    words.dedup();
+
+    Ok(words)
+}
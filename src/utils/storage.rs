@@ -0,0 +1,18 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Helper function for the directory where the player's own data (game history, settings, ...) is kept.
+/// This is the platform's user data directory, e.g. ~/.local/share/RustyWordle on Linux.
+/// If the platform has none, the data is kept next to the dictionaries in src/utils.
+pub fn data_dir() -> PathBuf {
+    let dir = match dirs::data_dir() {
+        Some(dir) => dir.join("RustyWordle"),
+        None => PathBuf::from("src/utils"),
+    };
+
+    if let Err(e) = fs::create_dir_all(&dir) {
+        println!("Error creating data directory {:?}: {}", dir, e);
+    }
+
+    dir
+}
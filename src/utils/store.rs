@@ -0,0 +1,76 @@
+use crate::utils::definition::Definition;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// File with the definitions of the cleaned dictionary, one JSON definition per line
+pub const DEFINITIONS_FILE: &str = "definitions.dat";
+
+/// File with the offset table of the definitions: a "word offset length" line per word, separated by tabs
+pub const INDEX_FILE: &str = "definitions.idx";
+
+/// Struct for the words of the cleaned dictionary together with where their definitions are on disk.
+/// Only the words and their offsets are held in memory, a definition is read from the definitions file when it is needed.
+#[derive(Debug, Clone)]
+pub struct DefinitionStore {
+    path: PathBuf,
+    offsets: HashMap<String, (u64, usize)>,
+}
+
+impl DefinitionStore {
+    /// Helper function that reads the offset table of the dictionary in the given directory
+    pub fn open(dir: &Path) -> Result<Self> {
+        let index = fs::read_to_string(dir.join(INDEX_FILE))?;
+        let mut offsets = HashMap::new();
+
+        for line in index.lines() {
+            let mut fields = line.split('\t');
+            let entry = match (fields.next(), fields.next(), fields.next()) {
+                (Some(word), Some(offset), Some(length)) => offset
+                    .parse::<u64>()
+                    .ok()
+                    .zip(length.parse::<usize>().ok())
+                    .map(|position| (word.to_string(), position)),
+                _ => None,
+            };
+
+            match entry {
+                Some((word, position)) => offsets.insert(word, position),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Invalid line in {}: {}", INDEX_FILE, line),
+                    ))
+                }
+            };
+        }
+
+        Ok(DefinitionStore {
+            path: dir.join(DEFINITIONS_FILE),
+            offsets,
+        })
+    }
+
+    /// Method for all words of the dictionary
+    pub fn words(&self) -> impl Iterator<Item = &String> {
+        self.offsets.keys()
+    }
+
+    /// Method that reads the definition of a word from disk
+    pub fn definition(&self, word: &str) -> Result<Definition> {
+        let Some(&(offset, length)) = self.offsets.get(word) else {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("{} is not in the dictionary", word),
+            ));
+        };
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut line = vec![0; length];
+        file.read_exact(&mut line)?;
+
+        Ok(serde_json::from_slice(&line)?)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::utils::storage::data_dir;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Result};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Seconds a speed run is charged for every guess
+pub const PENALTY_PER_GUESS: u64 = 5;
+
+/// Enum for the clock of a timed run
+/// - Countdown: solve as many words as possible before the minutes are up
+/// - Stopwatch: solve a single word as fast as possible, every guess adds a penalty (speed run)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerKind {
+    Countdown { minutes: u64 },
+    Stopwatch,
+}
+
+/// A word played during a timed run
+#[derive(Debug, Clone)]
+pub struct TimedWord {
+    pub word: String,
+    pub won: bool,
+    pub guesses: usize,
+}
+
+/// Struct for a timed run: the clock and the words played since it started
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+    pub kind: TimerKind,
+    pub word_length: usize,
+    started: Instant,
+    /// time on the clock when the run ended, None while it is running
+    pub finished: Option<Duration>,
+    pub words: Vec<TimedWord>,
+}
+
+impl TimedRun {
+    pub fn new(kind: TimerKind, word_length: usize) -> Self {
+        Self {
+            kind,
+            word_length,
+            started: Instant::now(),
+            finished: None,
+            words: Vec::new(),
+        }
+    }
+
+    /// Method for the time since the run started, a finished run keeps the time it ended with
+    pub fn elapsed(&self) -> Duration {
+        self.finished.unwrap_or_else(|| self.started.elapsed())
+    }
+
+    /// Method for the time left of a countdown, None for a stopwatch
+    pub fn remaining(&self) -> Option<Duration> {
+        match self.kind {
+            TimerKind::Countdown { minutes } => {
+                Some(Duration::from_secs(minutes * 60).saturating_sub(self.elapsed()))
+            }
+            TimerKind::Stopwatch => None,
+        }
+    }
+
+    /// Method to check if a countdown ran out
+    pub fn is_out_of_time(&self) -> bool {
+        self.remaining()
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+
+    /// Method that stops the clock
+    pub fn finish(&mut self) {
+        if self.finished.is_none() {
+            self.finished = Some(self.started.elapsed());
+        }
+    }
+
+    pub fn solved(&self) -> usize {
+        self.words.iter().filter(|word| word.won).count()
+    }
+
+    /// Method for the penalty of all guesses of the run
+    pub fn penalty(&self) -> Duration {
+        let guesses: usize = self.words.iter().map(|word| word.guesses).sum();
+        Duration::from_secs(guesses as u64 * PENALTY_PER_GUESS)
+    }
+
+    /// Method for the score of the run: solved words for a countdown, seconds plus penalty for a speed run.
+    /// A run without a solved word has no score.
+    pub fn score(&self) -> Option<f64> {
+        if self.solved() == 0 {
+            return None;
+        }
+
+        match self.kind {
+            TimerKind::Countdown { .. } => Some(self.solved() as f64),
+            TimerKind::Stopwatch => Some((self.elapsed() + self.penalty()).as_secs_f64()),
+        }
+    }
+
+    /// Method for the key the personal best of the run is stored with, e.g. "countdown-3-5" for 3 minutes with 5 letters
+    pub fn best_key(&self) -> String {
+        match self.kind {
+            TimerKind::Countdown { minutes } => {
+                format!("countdown-{}-{}", minutes, self.word_length)
+            }
+            TimerKind::Stopwatch => format!("speedrun-{}", self.word_length),
+        }
+    }
+
+    /// Method to check if a higher score is better, a speed run wants the lowest time
+    pub fn higher_is_better(&self) -> bool {
+        matches!(self.kind, TimerKind::Countdown { .. })
+    }
+}
+
+/// Helper function that formats a duration as minutes, seconds and tenths, e.g. 02:31.4
+pub fn clock(duration: Duration) -> String {
+    let tenths = duration.as_millis() / 100;
+    format!(
+        "{:02}:{:02}.{}",
+        tenths / 600,
+        tenths / 10 % 60,
+        tenths % 10
+    )
+}
+
+/// The best score for every timed mode and word length, kept in the user data directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersonalBests {
+    #[serde(default)]
+    pub bests: BTreeMap<String, f64>,
+}
+
+/// Helper function for the file that holds the personal bests
+fn personal_bests_path() -> PathBuf {
+    data_dir().join("personal_bests.json")
+}
+
+impl PersonalBests {
+    /// Helper function that reads the personal bests, a missing file means there are none yet
+    pub fn load() -> Result<Self> {
+        let path = personal_bests_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    /// Method that writes the personal bests back to the user data directory
+    pub fn save(&self) -> Result<()> {
+        let writer = BufWriter::new(File::create(personal_bests_path())?);
+        Ok(serde_json::to_writer_pretty(writer, self)?)
+    }
+
+    /// Method that keeps the score of a finished run if it beats the personal best, returns true for a new best
+    pub fn record(&mut self, run: &TimedRun) -> Result<bool> {
+        let Some(score) = run.score() else {
+            return Ok(false);
+        };
+
+        let key = run.best_key();
+        let better = match self.bests.get(&key) {
+            Some(&best) if run.higher_is_better() => score > best,
+            Some(&best) => score < best,
+            None => true,
+        };
+        if better {
+            self.bests.insert(key, score);
+            self.save()?;
+        }
+        Ok(better)
+    }
+}